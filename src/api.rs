@@ -0,0 +1,3259 @@
+use crate::{
+    commons::{
+        BindingDestinationType, FeatureFlagStability, QueueType, TimeUnit, UserLimitTarget,
+        UserTag, VirtualHostLimitTarget,
+    },
+    error::RequestContext,
+    requests::{
+        BindingSpec, EnforcedLimitParams, ExchangeParams, MessageProperties, Permissions,
+        PolicyParams, QueueArgumentsBuilder, QueueParams, RuntimeParameterDefinition,
+        ShovelDeleteAfter, ShovelParams, UserParams, VirtualHostParams, XArguments,
+    },
+    responses::{self, BindingInfo},
+    utils::{
+        self, percent_encode, BindindVertex, HttpVersion, Named, OverviewSampleRetentionPolicies,
+        OverviewVersion, PublishResult,
+    },
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, ACCEPT},
+    tls, Client as HttpClient, RequestBuilder, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+type HttpClientResponse = reqwest::Response;
+type HttpRequest = reqwest::Request;
+type RequestInspector<'a> = Arc<dyn Fn(&HttpRequest) + Send + Sync + 'a>;
+
+pub type Error = crate::error::Error<HttpClientResponse>;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An async client for the [RabbitMQ HTTP API](https://rabbitmq.com/management.html#http-api).
+/// It has the same functions and builder methods as [`crate::blocking::Client`]
+/// but is built on the non-blocking [`reqwest::Client`].
+///
+/// Most functions provided by this type represent various HTTP API operations.
+/// For example,
+///
+///  * the [`Client::get_queue_info`] function corresponds to the `GET /api/queues/{vhost}/{name}` endpoint
+///  * the [`Client::list_user_connections`] function corresponds to the `GET /api/connections/username/{username}` endpoint
+///
+/// and so on.
+///
+/// Example
+/// ```rust
+/// use rabbitmq_http_client::api::Client;
+///
+/// # async fn call() {
+/// let endpoint = "http://localhost:15672/api/";
+/// let username = "username";
+/// let password = "password";
+/// let rc = Client::new(&endpoint).with_basic_auth_credentials(&username, &password);
+/// // list cluster nodes
+/// let _ = rc.list_nodes().await;
+/// // list user connections
+/// let _ = rc.list_connections().await;
+/// // fetch information and metrics of a specific queue
+/// let _ = rc.get_queue_info("/", "qq.1").await;
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client<'a> {
+    endpoint: &'a str,
+    username: &'a str,
+    password: &'a str,
+    ca_certificate: Option<reqwest::Certificate>,
+    skip_tls_peer_verification: bool,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    http_version: HttpVersion,
+    accept: HeaderValue,
+    path_prefix: Option<String>,
+    preemptive_auth: bool,
+    lenient_deserialization: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    request_inspector: Option<RequestInspector<'a>>,
+    http_client: Arc<OnceLock<HttpClient>>,
+}
+
+impl<'a> Client<'a> {
+    /// Instantiates a client for the specified endpoint.
+    /// Credentials default to guest/guest.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint);
+    /// ```
+    pub fn new(endpoint: &'a str) -> Self {
+        Self {
+            endpoint,
+            username: "guest",
+            password: "guest",
+            ca_certificate: None,
+            skip_tls_peer_verification: false,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            timeout: None,
+            connect_timeout: None,
+            http_version: HttpVersion::Negotiated,
+            accept: HeaderValue::from_static("application/json"),
+            path_prefix: None,
+            preemptive_auth: true,
+            lenient_deserialization: false,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            request_inspector: None,
+            http_client: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Configures basic HTTP Auth for authentication.
+    ///
+    /// By default, credentials are sent preemptively with every request,
+    /// see [`Client::with_preemptive_auth`].
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let username = "username";
+    /// let password = "password";
+    /// let rc = Client::new(&endpoint).with_basic_auth_credentials(&username, &password);
+    /// ```
+    pub fn with_basic_auth_credentials(mut self, username: &'a str, password: &'a str) -> Self {
+        self.username = username;
+        self.password = password;
+        self
+    }
+
+    /// Configures a custom CA Certificate for TLS peer certificate chain verification.
+    ///
+    /// Example
+    /// ```rust
+    /// # use rabbitmq_http_client::api::Client;
+    /// # use std::fs::File;
+    /// # use std::io::Read;
+    /// # fn call() -> Result<(), Box<dyn std::error::Error>> {
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let mut buf = Vec::new();
+    /// File::open("ca_certificate.pem")?.read_to_end(&mut buf)?;
+    /// let rc = Client::new(&endpoint).with_pem_ca_certificate(buf);
+    /// # drop(call);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_pem_ca_certificate(mut self, ca_certificate: Vec<u8>) -> Result<Self> {
+        self.ca_certificate = Some(reqwest::Certificate::from_pem(&ca_certificate)?);
+        self.http_client = Arc::new(OnceLock::new());
+        Ok(self)
+    }
+
+    /// Configures a custom CA Certificate for TLS peer certificate chain verification.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).without_tls_peer_verification();
+    /// ```
+    pub fn without_tls_peer_verification(mut self) -> Self {
+        self.skip_tls_peer_verification = true;
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Configures how long idle pooled HTTP connections are kept open.
+    /// Long-running processes that poll the API periodically should use a value
+    /// lower than the idle timeout of the node or any proxy in front of it.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    /// use std::time::Duration;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_pool_idle_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Configures the maximum number of idle pooled HTTP connections per host.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_pool_max_idle_per_host(2);
+    /// ```
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Configures how long a request may take in total, from connecting to reading
+    /// the response body. Requests that take longer fail with [`Error::RequestError`].
+    ///
+    /// By default, no timeout is configured on the HTTP client.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    /// use std::time::Duration;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Configures how long connecting to the endpoint may take.
+    /// Applies in addition to [`Client::with_timeout`].
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    /// use std::time::Duration;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_connect_timeout(Duration::from_secs(3));
+    /// ```
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Forces HTTP/2 without an upgrade negotiation ("prior knowledge").
+    /// By default, the HTTP protocol version is negotiated.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_http2_prior_knowledge();
+    /// ```
+    pub fn with_http2_prior_knowledge(mut self) -> Self {
+        self.http_version = HttpVersion::Http2PriorKnowledge;
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Restricts the client to HTTP/1.x, for reverse proxies that mishandle HTTP/2.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_http1_only();
+    /// ```
+    pub fn with_http1_only(mut self) -> Self {
+        self.http_version = HttpVersion::Http1Only;
+        self.http_client = Arc::new(OnceLock::new());
+        self
+    }
+
+    /// Overrides the `Accept` header sent with every request, `application/json` by default.
+    ///
+    /// Sending an explicit `Accept` header also keeps reverse proxies that negotiate content
+    /// from responding with HTML error pages.
+    ///
+    /// Example
+    /// ```rust
+    /// # use rabbitmq_http_client::api::Client;
+    /// # fn call() -> Result<(), Box<dyn std::error::Error>> {
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_accept_header("application/json;charset=utf-8")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_accept_header(mut self, value: &str) -> Result<Self> {
+        self.accept = HeaderValue::from_str(value)?;
+        self.http_client = Arc::new(OnceLock::new());
+        Ok(self)
+    }
+
+    /// Controls whether credentials are sent with every request (the default)
+    /// or only after the server responds with a `401 Unauthorized` challenge.
+    ///
+    /// Some reverse proxies in front of the management API issue their own challenge
+    /// and expect the challenge-response flow. Disabling preemptive authentication
+    /// costs an extra round trip for every request, so only do it when a proxy requires it.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_preemptive_auth(false);
+    /// ```
+    pub fn with_preemptive_auth(mut self, enabled: bool) -> Self {
+        self.preemptive_auth = enabled;
+        self
+    }
+
+    /// Makes the client tolerate response fields it cannot deserialize.
+    ///
+    /// A field that fails to deserialize, for example, because its type changed in
+    /// a newer RabbitMQ version, falls back to its default (`None` for optional fields)
+    /// and a warning is logged using the [`log`](https://docs.rs/log) crate.
+    /// A list element that cannot be deserialized without that field is skipped,
+    /// so one unexpected queue does not fail [`Client::list_queues`]. Every list element
+    /// is handled on its own and each response produces at most one warning.
+    ///
+    /// Disabled by default: responses that do not match the expected types are reported
+    /// as [`Error::DeserializationError`] in either mode.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_lenient_deserialization(true);
+    /// ```
+    pub fn with_lenient_deserialization(mut self, enabled: bool) -> Self {
+        self.lenient_deserialization = enabled;
+        self
+    }
+
+    /// Retries `GET` requests that fail with a connection error or a `503 Service Unavailable`
+    /// response up to `max_retries` times, waiting `base_delay` before the first retry
+    /// and twice as long before every next one. Returns the last error if all attempts fail.
+    ///
+    /// Useful when polling a node that is (re)starting. Requests that modify state
+    /// are never retried, and neither are health checks, which report failures with a 503.
+    /// By default, no requests are retried.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    /// use std::time::Duration;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_retries(5, Duration::from_millis(200));
+    /// ```
+    pub fn with_retries(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_base_delay = base_delay;
+        self
+    }
+
+    /// Registers a function that is called with every outgoing request,
+    /// for example, to log requests or to emit metrics and tracing spans.
+    ///
+    /// The function receives a copy of the request with the method, URL and headers
+    /// but without the `Authorization` header, any credentials in the URL, or the body.
+    /// It cannot modify the request that is sent.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "http://localhost:15672/api/";
+    /// let rc = Client::new(&endpoint).with_request_inspector(|req| {
+    ///     println!("{} {}", req.method(), req.url().path());
+    /// });
+    /// ```
+    pub fn with_request_inspector<F>(mut self, inspector: F) -> Self
+    where
+        F: Fn(&HttpRequest) + Send + Sync + 'a,
+    {
+        self.request_inspector = Some(Arc::new(inspector));
+        self
+    }
+
+    /// Derives a client that targets a different cluster behind the same gateway.
+    /// The derived client has the same credentials and HTTP settings
+    /// but inserts `prefix` right after the endpoint's host.
+    /// It shares the underlying HTTP client and its connection pool with this client
+    /// until either of them changes an HTTP setting.
+    ///
+    /// For example, with an endpoint of `https://gateway.local/api`,
+    /// `for_cluster("cluster-a")` sends requests to `https://gateway.local/cluster-a/api/...`.
+    ///
+    /// Example
+    /// ```rust
+    /// use rabbitmq_http_client::api::Client;
+    ///
+    /// let endpoint = "https://gateway.local/api";
+    /// let rc = Client::new(&endpoint);
+    /// let cluster_a = rc.for_cluster("cluster-a");
+    /// let cluster_b = rc.for_cluster("cluster-b");
+    /// ```
+    pub fn for_cluster(&self, prefix: &str) -> Self {
+        let mut client = self.clone();
+        client.path_prefix = Some(prefix.trim_matches('/').to_owned());
+        client
+    }
+
+    /// Checks that the API is reachable and accepts the configured credentials
+    /// using a single cheap authenticated request (`GET /api/whoami`).
+    ///
+    /// Returns [`Error::RequestError`] if the API cannot be reached,
+    /// [`Error::AuthenticationFailed`] if the credentials are rejected
+    /// and [`Error::AccessRefused`] if the user is not allowed to use the HTTP API.
+    pub async fn verify_connection(&self) -> Result<()> {
+        let response = self.http_get("whoami").await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Returns the user the client is authenticated as and its tags.
+    /// Unlike [`Client::get_user`], this does not require the `administrator` tag.
+    pub async fn whoami(&self) -> Result<responses::CurrentUser> {
+        let response = self.http_get("whoami").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::CurrentUser>(response2)
+            .await
+    }
+
+    /// Checks whether the given credentials are accepted by the HTTP API, for example,
+    /// in a login flow. Returns the user and its tags if they are, and `None` if they are not.
+    ///
+    /// The request is authenticated with the given credentials instead of the client's own,
+    /// which are left unchanged. Users without any tags are refused access to the HTTP API,
+    /// which is reported as [`Error::AccessRefused`].
+    pub async fn authenticate(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<responses::CurrentUser>> {
+        let client = self.http_client()?;
+        let request = client
+            .get(self.rooted_path("whoami"))
+            .basic_auth(username, Some(password));
+        let response = self.execute(client, request).await?;
+        match self.ok_or_status_code_error(response) {
+            Ok(response2) => self
+                .deserialize_response::<responses::CurrentUser>(response2)
+                .await
+                .map(Some),
+            Err(Error::AuthenticationFailed(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Lists cluster nodes.
+    pub async fn list_nodes(&self) -> Result<Vec<responses::ClusterNode>> {
+        let response = self.http_get("nodes").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::ClusterNode>(response2)
+            .await
+    }
+
+    /// Lists cluster node names. Only the names are requested (using `columns=name`),
+    /// which is much cheaper than [`Client::list_nodes`].
+    pub async fn list_node_names(&self) -> Result<Vec<String>> {
+        let response = self
+            .http_get_with_query("nodes", &[("columns", "name")])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        let nodes = self.deserialize_list_response::<Named>(response2).await?;
+        Ok(nodes.into_iter().map(|n| n.name).collect())
+    }
+
+    /// Lists virtual hosts in the cluster.
+    pub async fn list_vhosts(&self) -> Result<Vec<responses::VirtualHost>> {
+        let response = self.http_get("vhosts").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::VirtualHost>(response2)
+            .await
+    }
+
+    /// Makes `queue_type` the default queue type of every virtual host in the cluster,
+    /// which is what clients get when they declare a queue without the `x-queue-type` argument.
+    ///
+    /// RabbitMQ has no cluster-wide setting for this that can be changed over the HTTP API,
+    /// so this updates the metadata of every existing virtual host, preserving
+    /// their descriptions, tags and tracing settings. Virtual hosts created later
+    /// must specify [`VirtualHostParams::default_queue_type`] themselves.
+    ///
+    /// A virtual host that cannot be updated does not stop the others from being updated.
+    /// Returns the virtual hosts that could not be updated along with the errors,
+    /// an empty list means every virtual host now uses `queue_type`.
+    ///
+    /// Per-virtual host default queue types require RabbitMQ 3.11 or later,
+    /// older versions make this return [`Error::UnsupportedServerVersion`].
+    pub async fn set_cluster_default_queue_type(
+        &self,
+        queue_type: QueueType,
+    ) -> Result<Vec<(String, Error)>> {
+        let response = self
+            .http_get_with_query("overview", &[("columns", "rabbitmq_version")])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        let version = self
+            .deserialize_response::<OverviewVersion>(response2)
+            .await?
+            .rabbitmq_version;
+        if !utils::version_at_least(&version, 3, 11) {
+            return Err(Error::UnsupportedServerVersion {
+                required: "3.11".to_owned(),
+                actual: version,
+            });
+        }
+
+        let mut failed = Vec::new();
+        for vh in self.list_vhosts().await? {
+            if vh.default_queue_type.as_ref() == Some(&queue_type) {
+                continue;
+            }
+            let params = VirtualHostParams {
+                name: &vh.name,
+                description: vh.description.as_deref(),
+                tags: vh
+                    .tags
+                    .as_ref()
+                    .map(|tags| tags.iter().map(String::as_str).collect()),
+                default_queue_type: Some(queue_type.clone()),
+                tracing: vh.tracing,
+            };
+            if let Err(err) = self.update_vhost(&params).await {
+                failed.push((vh.name, err));
+            }
+        }
+        Ok(failed)
+    }
+
+    /// Lists virtual hosts that use the given default queue type.
+    pub async fn list_vhosts_with_default_queue_type(
+        &self,
+        queue_type: QueueType,
+    ) -> Result<Vec<responses::VirtualHost>> {
+        let vhosts = self.list_vhosts().await?;
+        Ok(vhosts
+            .into_iter()
+            .filter(|vh| vh.default_queue_type.as_ref() == Some(&queue_type))
+            .collect())
+    }
+
+    /// Lists users in the internal database.
+    pub async fn list_users(&self) -> Result<Vec<responses::User>> {
+        let response = self.http_get("users").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::User>(response2)
+            .await
+    }
+
+    /// Lists users in the internal database that have the given tag.
+    pub async fn list_users_with_tag(&self, tag: &UserTag) -> Result<Vec<responses::User>> {
+        let tag = String::from(tag.clone());
+        let users = self.list_users().await?;
+        Ok(users
+            .into_iter()
+            .filter(|u| u.tags.contains(&tag))
+            .collect())
+    }
+
+    /// Lists all client connections across the cluster.
+    pub async fn list_connections(&self) -> Result<Vec<responses::Connection>> {
+        let response = self.http_get("connections").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Connection>(response2)
+            .await
+    }
+
+    /// Lists all client connections across the cluster, requesting only the given `columns`
+    /// (for example, `&["name", "vhost"]`) to reduce the size of the response.
+    ///
+    /// The responses lack all other fields, so they must be deserialized into a type
+    /// that only expects the requested columns, such as `serde_json::Map<String, Value>`
+    /// or a purpose-built struct.
+    pub async fn list_connections_with_columns<T>(&self, columns: &[&str]) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .http_get_with_query("connections", &[("columns", &columns.join(","))])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<T>(response2).await
+    }
+
+    /// Lists connections whose client library (the `product` client property,
+    /// e.g. "RabbitMQ .NET" or "Bunny") contains `product`. Matching is case-insensitive.
+    pub async fn list_connections_by_product(
+        &self,
+        product: &str,
+    ) -> Result<Vec<responses::Connection>> {
+        let needle = product.to_lowercase();
+        let connections = self.list_connections().await?;
+        Ok(connections
+            .into_iter()
+            .filter(|c| c.client_properties.product.to_lowercase().contains(&needle))
+            .collect())
+    }
+
+    pub async fn get_connection_info(&self, name: &str) -> Result<responses::Connection> {
+        let response = self
+            .http_get(&format!("connections/{}", percent_encode(name)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Connection>(response2)
+            .await
+    }
+
+    pub async fn close_connection(&self, name: &str, reason: Option<&str>) -> Result<()> {
+        self.close_connection_if_exists(name, reason).await?;
+        Ok(())
+    }
+
+    /// Closes all connections of a user, for example, after revoking their access.
+    /// Returns the number of connections that were closed, which does not include
+    /// the ones that closed on their own in the meantime.
+    pub async fn close_user_connections(
+        &self,
+        username: &str,
+        reason: Option<&str>,
+    ) -> Result<usize> {
+        let connections = self.list_user_connections(username).await?;
+        let mut closed = 0;
+        for c in connections {
+            if self.close_connection_if_exists(&c.name, reason).await? {
+                closed += 1;
+            }
+        }
+        Ok(closed)
+    }
+
+    /// Closes all client connections that were opened more than `age` ago.
+    /// Returns the number of connections that were closed.
+    pub async fn close_connections_older_than(
+        &self,
+        age: Duration,
+        reason: Option<&str>,
+    ) -> Result<usize> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let threshold = age.as_millis() as u64;
+
+        let connections = self.list_connections().await?;
+        let mut closed = 0;
+        for c in connections
+            .iter()
+            .filter(|c| now.saturating_sub(c.connected_at) > threshold)
+        {
+            self.close_connection(&c.name, reason).await?;
+            closed += 1;
+        }
+        Ok(closed)
+    }
+
+    /// Lists all connections in the given virtual host.
+    pub async fn list_connections_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::Connection>> {
+        let response = self
+            .http_get(&format!(
+                "vhosts/{}/connections",
+                percent_encode(virtual_host)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Connection>(response2)
+            .await
+    }
+
+    /// Lists all connections in the given virtual host, passing the provided
+    /// query parameters through to the API as is.
+    pub async fn list_connections_with_params(
+        &self,
+        virtual_host: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Vec<responses::Connection>> {
+        let response = self
+            .http_get_with_query(
+                &format!("vhosts/{}/connections", percent_encode(virtual_host)),
+                params,
+            )
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Connection>(response2)
+            .await
+    }
+
+    /// Lists all connections of a specific user.
+    pub async fn list_user_connections(
+        &self,
+        username: &str,
+    ) -> Result<Vec<responses::UserConnection>> {
+        let response = self
+            .http_get(&format!(
+                "connections/username/{}",
+                percent_encode(username)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::UserConnection>(response2)
+            .await
+    }
+
+    /// Lists all channels across the cluster.
+    pub async fn list_channels(&self) -> Result<Vec<responses::Channel>> {
+        let response = self.http_get("channels").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Channel>(response2)
+            .await
+    }
+
+    /// Lists channels with more than `threshold` unacknowledged messages,
+    /// which usually indicates a stuck or overwhelmed consumer.
+    ///
+    /// When message stats collection is disabled, channels report no unacknowledged
+    /// messages and nothing is returned.
+    pub async fn list_channels_with_unacked_above(
+        &self,
+        threshold: u32,
+    ) -> Result<Vec<responses::Channel>> {
+        let channels = self.list_channels().await?;
+        Ok(channels
+            .into_iter()
+            .filter(|ch| ch.messages_unacknowledged > threshold)
+            .collect())
+    }
+
+    /// Lists all channels in the given virtual host.
+    pub async fn list_channels_in(&self, virtual_host: &str) -> Result<Vec<responses::Channel>> {
+        let response = self
+            .http_get(&format!("vhosts/{}/channels", percent_encode(virtual_host)))
+            .await?;
+
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Channel>(response2)
+            .await
+    }
+
+    /// Returns information about a channel.
+    pub async fn get_channel_info(&self, name: &str) -> Result<responses::Channel> {
+        let response = self
+            .http_get(&format!("channels/{}", percent_encode(name)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Channel>(response2)
+            .await
+    }
+
+    /// Closes a channel.
+    ///
+    /// The HTTP API cannot close an individual channel, so this closes the connection
+    /// that owns the channel instead. **All other channels on that connection are closed, too.**
+    /// Like [`Client::close_connection`], this is a no-op if the channel does not exist.
+    pub async fn close_channel(&self, name: &str, reason: Option<&str>) -> Result<()> {
+        let channel = match self.get_channel_info(name).await {
+            Ok(ch) => ch,
+            Err(Error::ClientErrorResponse(404, _)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        self.close_connection(&channel.connection_details.name, reason)
+            .await
+    }
+
+    /// Lists all queues and streams across the cluster.
+    pub async fn list_queues(&self) -> Result<Vec<responses::QueueInfo>> {
+        let response = self.http_get("queues").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::QueueInfo>(response2)
+            .await
+    }
+
+    /// Lists all queues and streams across the cluster, requesting only the given `columns`
+    /// (for example, `&["name", "vhost"]`) to reduce the size of the response.
+    ///
+    /// The responses lack all other fields, so they must be deserialized into a type
+    /// that only expects the requested columns, such as `serde_json::Map<String, Value>`
+    /// or a purpose-built struct.
+    pub async fn list_queues_with_columns<T>(&self, columns: &[&str]) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .http_get_with_query("queues", &[("columns", &columns.join(","))])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<T>(response2).await
+    }
+
+    /// Lists all queues and streams in the given virtual host.
+    pub async fn list_queues_in(&self, virtual_host: &str) -> Result<Vec<responses::QueueInfo>> {
+        let response = self
+            .http_get(&format!("queues/{}", percent_encode(virtual_host)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::QueueInfo>(response2)
+            .await
+    }
+
+    /// Lists all queues and streams in the given virtual host, passing the provided
+    /// query parameters (for example, `sort` or `lengths_age`) through to the API as is.
+    ///
+    /// The results are parsed as [`responses::QueueInfo`], so the parameters must not
+    /// leave out any of its fields. To fetch a subset of fields, use
+    /// [`Client::list_queues_with_columns`] instead.
+    pub async fn list_queues_with_params(
+        &self,
+        virtual_host: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let response = self
+            .http_get_with_query(&format!("queues/{}", percent_encode(virtual_host)), params)
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::QueueInfo>(response2)
+            .await
+    }
+
+    /// Lists all queues (or streams) of the given type across the cluster.
+    ///
+    /// The API cannot filter queues by type, so all queues are fetched
+    /// and filtered on the client side.
+    pub async fn list_queues_of_type(
+        &self,
+        queue_type: QueueType,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let mut queues = self.list_queues().await?;
+        queues.retain(|q| q.queue_type == queue_type);
+        Ok(queues)
+    }
+
+    /// Lists all queues (or streams) of the given type in the given virtual host.
+    /// Like [`Client::list_queues_of_type`], this filters on the client side.
+    pub async fn list_queues_of_type_in(
+        &self,
+        virtual_host: &str,
+        queue_type: QueueType,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let mut queues = self.list_queues_in(virtual_host).await?;
+        queues.retain(|q| q.queue_type == queue_type);
+        Ok(queues)
+    }
+
+    /// Lists quorum queues and streams whose leader replica is hosted on the given node.
+    /// Classic queues do not have a leader and are never included.
+    pub async fn queues_with_leader_on(&self, node: &str) -> Result<Vec<responses::QueueInfo>> {
+        let queues = self.list_queues().await?;
+        Ok(queues
+            .into_iter()
+            .filter(|q| q.leader.as_deref() == Some(node))
+            .collect())
+    }
+
+    /// Lists exclusive queues in the given virtual host, or in all virtual hosts if `None`.
+    ///
+    /// Exclusive queues belong to the connection that declared them, so they cannot be
+    /// migrated or re-declared elsewhere, and are usually excluded from such plans.
+    pub async fn list_exclusive_queues(
+        &self,
+        virtual_host: Option<&str>,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let queues = match virtual_host {
+            Some(vh) => self.list_queues_in(vh).await?,
+            None => self.list_queues().await?,
+        };
+        Ok(queues.into_iter().filter(|q| q.exclusive).collect())
+    }
+
+    /// Lists quorum queues that have fewer than `expected_replicas` members
+    /// or have some of their members offline.
+    pub async fn under_replicated_quorum_queues(
+        &self,
+        expected_replicas: usize,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let queues = self.list_queues().await?;
+        Ok(queues
+            .into_iter()
+            .filter(|q| q.queue_type == QueueType::Quorum)
+            .filter(|q| {
+                q.member_count() < expected_replicas || q.online_member_count() < q.member_count()
+            })
+            .collect())
+    }
+
+    /// Lists queues and streams in the given virtual host that the named policy
+    /// is currently applied to, as reported by the node.
+    pub async fn list_queues_with_policy(
+        &self,
+        virtual_host: &str,
+        policy: &str,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let queues = self.list_queues_in(virtual_host).await?;
+        Ok(queues
+            .into_iter()
+            .filter(|q| q.policy.as_deref() == Some(policy))
+            .collect())
+    }
+
+    /// Lists all exchanges across the cluster.
+    pub async fn list_exchanges(&self) -> Result<Vec<responses::ExchangeInfo>> {
+        let response = self.http_get("exchanges").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::ExchangeInfo>(response2)
+            .await
+    }
+
+    /// Lists all exchanges across the cluster, requesting only the given `columns`
+    /// (for example, `&["name", "vhost"]`) to reduce the size of the response.
+    ///
+    /// The responses lack all other fields, so they must be deserialized into a type
+    /// that only expects the requested columns, such as `serde_json::Map<String, Value>`
+    /// or a purpose-built struct.
+    pub async fn list_exchanges_with_columns<T>(&self, columns: &[&str]) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self
+            .http_get_with_query("exchanges", &[("columns", &columns.join(","))])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<T>(response2).await
+    }
+
+    /// Lists all exchanges in the given virtual host.
+    pub async fn list_exchanges_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::ExchangeInfo>> {
+        let response = self
+            .http_get(&format!("exchanges/{}", percent_encode(virtual_host)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::ExchangeInfo>(response2)
+            .await
+    }
+
+    /// Follows `x-alternate-exchange` arguments starting at `exchange` and returns
+    /// the alternate exchanges in the order messages would fall through them.
+    /// The starting exchange itself is not included.
+    ///
+    /// Returns [`Error::AlternateExchangeCycle`] if the chain loops back on itself.
+    pub async fn alternate_exchange_chain(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+    ) -> Result<Vec<String>> {
+        let exchanges = self.list_exchanges_in(virtual_host).await?;
+        utils::alternate_exchange_chain(exchanges, exchange).map_err(Error::AlternateExchangeCycle)
+    }
+
+    /// Lists all exchanges in the given virtual host, passing the provided
+    /// query parameters through to the API as is.
+    pub async fn list_exchanges_with_params(
+        &self,
+        virtual_host: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Vec<responses::ExchangeInfo>> {
+        let response = self
+            .http_get_with_query(
+                &format!("exchanges/{}", percent_encode(virtual_host)),
+                params,
+            )
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::ExchangeInfo>(response2)
+            .await
+    }
+
+    /// Lists all bindings (both queue-to-exchange and exchange-to-exchange ones) across the cluster.
+    pub async fn list_bindings(&self) -> Result<Vec<responses::BindingInfo>> {
+        let response = self.http_get("bindings").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::BindingInfo>(response2)
+            .await
+    }
+
+    /// Lists all bindings (both queue-to-exchange and exchange-to-exchange ones)  in the given virtual host.
+    pub async fn list_bindings_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let response = self
+            .http_get(&format!("bindings/{}", percent_encode(virtual_host)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::BindingInfo>(response2)
+            .await
+    }
+
+    /// Lists all bindings of a specific queue.
+    pub async fn list_queue_bindings(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let response = self
+            .http_get(&format!(
+                "queues/{}/{}/bindings",
+                percent_encode(virtual_host),
+                percent_encode(queue)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::BindingInfo>(response2)
+            .await
+    }
+
+    /// Lists bindings of a specific queue, excluding the implicit binding
+    /// to the default exchange that every queue has.
+    pub async fn list_queue_bindings_excluding_default(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let bindings = self.list_queue_bindings(virtual_host, queue).await?;
+        Ok(bindings
+            .into_iter()
+            .filter(|b| !b.source.is_empty())
+            .collect())
+    }
+
+    /// Lists all bindings of a specific exchange where it is the source.
+    pub async fn list_exchange_bindings_with_source(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        self.list_exchange_bindings_with_source_or_destination(
+            virtual_host,
+            exchange,
+            BindindVertex::Source,
+        )
+        .await
+    }
+
+    /// Resolves the queues a message published to `exchange` with `routing_key` would be routed to,
+    /// following exchange-to-exchange bindings.
+    ///
+    /// Routing is evaluated client-side for the default, direct, fanout and topic exchange types.
+    /// Other exchange types (e.g. headers) route based on information other than the routing key
+    /// and do not contribute any destinations. Alternate exchanges are not considered.
+    pub async fn routing_destinations(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+        routing_key: &str,
+    ) -> Result<Vec<String>> {
+        let mut queues = BTreeSet::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![exchange.to_owned()];
+        while let Some(exchange) = pending.pop() {
+            if !visited.insert(exchange.clone()) {
+                continue;
+            }
+
+            // the default exchange routes to the queue named after the routing key
+            if exchange.is_empty() {
+                match self.get_queue_info(virtual_host, routing_key).await {
+                    Ok(_) => {
+                        queues.insert(routing_key.to_owned());
+                    }
+                    Err(Error::ClientErrorResponse(404, _)) => (),
+                    Err(e) => return Err(e),
+                }
+                continue;
+            }
+
+            let x = self.get_exchange_info(virtual_host, &exchange).await?;
+            let bindings = self
+                .list_exchange_bindings_with_source(virtual_host, &exchange)
+                .await?;
+            for b in bindings {
+                if !utils::routes_over_binding(&x.exchange_type, &b.routing_key, routing_key) {
+                    continue;
+                }
+                match b.destination_type {
+                    BindingDestinationType::Queue => {
+                        queues.insert(b.destination);
+                    }
+                    BindingDestinationType::Exchange => pending.push(b.destination),
+                }
+            }
+        }
+        Ok(queues.into_iter().collect())
+    }
+
+    /// Lists all bindings of a specific exchange where it is the destination.
+    pub async fn list_exchange_bindings_with_destination(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        self.list_exchange_bindings_with_source_or_destination(
+            virtual_host,
+            exchange,
+            BindindVertex::Destination,
+        )
+        .await
+    }
+
+    /// Lists all consumers across the cluster.
+    pub async fn list_consumers(&self) -> Result<Vec<responses::Consumer>> {
+        let response = self.http_get("consumers").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Consumer>(response2)
+            .await
+    }
+
+    /// Lists all consumers in the given virtual host.
+    pub async fn list_consumers_in(&self, virtual_host: &str) -> Result<Vec<responses::Consumer>> {
+        let response = self
+            .http_get(&format!("consumers/{}", percent_encode(virtual_host)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Consumer>(response2)
+            .await
+    }
+
+    /// Lists consumers in a virtual host that have no prefetch limit (a prefetch of zero).
+    /// Such consumers can starve other consumers and cause memory spikes.
+    pub async fn list_unbounded_prefetch_consumers_in(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::Consumer>> {
+        let consumers = self.list_consumers_in(virtual_host).await?;
+        Ok(consumers
+            .into_iter()
+            .filter(|c| c.prefetch_count == 0)
+            .collect())
+    }
+
+    /// Returns the connection that a consumer uses, for example,
+    /// to inspect or close the connection of a misbehaving consumer.
+    pub async fn consumer_connection(
+        &self,
+        consumer: &responses::Consumer,
+    ) -> Result<responses::Connection> {
+        self.get_connection_info(consumer.connection_name()).await
+    }
+
+    /// Returns information about a cluster node.
+    pub async fn get_node_info(&self, name: &str) -> Result<responses::ClusterNode> {
+        let response = self.http_get(&format!("nodes/{}", name)).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::ClusterNode>(response2)
+            .await
+    }
+
+    /// Returns information about a virtual host.
+    pub async fn get_vhost(&self, name: &str) -> Result<responses::VirtualHost> {
+        let response = self
+            .http_get(&format!("vhosts/{}", percent_encode(name)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::VirtualHost>(response2)
+            .await
+    }
+
+    /// Returns information about a user in the internal database.
+    pub async fn get_user(&self, name: &str) -> Result<responses::User> {
+        let response = self
+            .http_get(&format!("users/{}", percent_encode(name)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::User>(response2)
+            .await
+    }
+
+    /// Returns information about a queue or stream.
+    pub async fn get_queue_info(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<responses::QueueInfo> {
+        let response = self
+            .http_get(&format!(
+                "queues/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(name)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::QueueInfo>(response2)
+            .await
+    }
+
+    /// Returns the role and availability of every replica of a quorum queue or stream,
+    /// see [`responses::QueueInfo::replica_statuses`].
+    pub async fn get_queue_replica_statuses(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<Vec<responses::QueueReplicaStatus>> {
+        let queue = self.get_queue_info(virtual_host, name).await?;
+        Ok(queue.replica_statuses())
+    }
+
+    /// A scaling signal for autoscalers: returns `true` when a queue has a backlog of at least
+    /// `backlog_threshold` messages and its consumers are not keeping up.
+    ///
+    /// A queue with a backlog and no consumers at all always needs more consumers.
+    /// Otherwise, consumers are considered saturated when consumer utilisation
+    /// (the share of time the queue can deliver to consumers immediately)
+    /// is at or below `max_utilisation`, for example, `0.9`.
+    pub async fn queue_needs_more_consumers(
+        &self,
+        virtual_host: &str,
+        name: &str,
+        backlog_threshold: u64,
+        max_utilisation: f32,
+    ) -> Result<bool> {
+        let q = self.get_queue_info(virtual_host, name).await?;
+        if q.message_count < backlog_threshold {
+            return Ok(false);
+        }
+        if q.consumer_count == 0 {
+            return Ok(true);
+        }
+        Ok(q.consumer_utilisation <= max_utilisation)
+    }
+
+    /// Returns information about an exchange.
+    pub async fn get_exchange_info(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<responses::ExchangeInfo> {
+        let response = self
+            .http_get(&format!(
+                "exchanges/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(name)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::ExchangeInfo>(response2)
+            .await
+    }
+
+    /// Creates a virtual host.
+    ///
+    /// See [`VirtualHostParams`]
+    pub async fn create_vhost(&self, params: &VirtualHostParams<'_>) -> Result<()> {
+        self.update_vhost(params).await
+    }
+
+    /// Creates a virtual host or updates metadata of an existing one.
+    ///
+    /// See [`VirtualHostParams`]
+    pub async fn update_vhost(&self, params: &VirtualHostParams<'_>) -> Result<()> {
+        let response = self
+            .http_put(&format!("vhosts/{}", percent_encode(params.name)), params)
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Adds a user to the internal database.
+    ///
+    /// See [`UserParams`] and [`crate::password_hashing`].
+    pub async fn create_user(&self, params: &UserParams<'_>) -> Result<()> {
+        let response = self
+            .http_put(&format!("users/{}", percent_encode(params.name)), params)
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Adds multiple users to the internal database.
+    ///
+    /// Unlike [`Client::create_user`], this function does not stop at the first failure.
+    /// It returns a result for every user, in the same order as `users`,
+    /// so that the failed ones can be retried.
+    pub async fn create_users(&self, users: &[UserParams<'_>]) -> Vec<Result<()>> {
+        let mut results = Vec::with_capacity(users.len());
+        for params in users {
+            results.push(self.create_user(params).await);
+        }
+        results
+    }
+
+    pub async fn declare_permissions(&self, params: &Permissions<'_>) -> Result<()> {
+        let response = self
+            .http_put(
+                // /api/permissions/vhost/user
+                &format!(
+                    "permissions/{}/{}",
+                    percent_encode(params.vhost),
+                    percent_encode(params.user)
+                ),
+                params,
+            )
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn grant_permissions(&self, vhost: &str, user: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "permissions/{}/{}",
+                percent_encode(vhost),
+                percent_encode(user)
+            ))
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn declare_queue(&self, virtual_host: &str, params: &QueueParams<'_>) -> Result<()> {
+        let response = self
+            .http_put(
+                &format!(
+                    "queues/{}/{}",
+                    percent_encode(virtual_host),
+                    percent_encode(params.name)
+                ),
+                params,
+            )
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Like [`Client::declare_queue`] but if the queue already exists with different properties,
+    /// fetches it and returns an [`Error::Inequivalent`] that lists the differing properties.
+    pub async fn declare_queue_checked(
+        &self,
+        virtual_host: &str,
+        params: &QueueParams<'_>,
+    ) -> Result<()> {
+        match self.declare_queue(virtual_host, params).await {
+            // RabbitMQ reports an inequivalent declaration as a 400 or a 406,
+            // depending on the version
+            Err(Error::ClientErrorResponse(status, response)) if status == 400 || status == 406 => {
+                let Ok(existing) = self.get_queue_info(virtual_host, params.name).await else {
+                    return Err(Error::ClientErrorResponse(status, response));
+                };
+                let differing_fields = utils::queue_differing_fields(&existing, params);
+                if differing_fields.is_empty() {
+                    return Err(Error::ClientErrorResponse(status, response));
+                }
+                Err(Error::Inequivalent {
+                    existing: Box::new(existing),
+                    requested: params.into(),
+                    differing_fields,
+                })
+            }
+            other => other,
+        }
+    }
+
+    pub async fn declare_exchange(
+        &self,
+        virtual_host: &str,
+        params: &ExchangeParams<'_>,
+    ) -> Result<()> {
+        let response = self
+            .http_put(
+                &format!(
+                    "exchanges/{}/{}",
+                    percent_encode(virtual_host),
+                    percent_encode(params.name)
+                ),
+                params,
+            )
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Declares a queue named `name` that receives the trace events of a virtual host.
+    ///
+    /// When tracing is enabled for a virtual host (see [`VirtualHostParams::tracing`]),
+    /// RabbitMQ publishes an event for every message published and delivered in it to
+    /// the `amq.rabbitmq.trace` topic exchange. The events can then be consumed
+    /// from the queue with an AMQP client. Tools that consume trace events independently
+    /// should each use a queue of their own, otherwise they will compete for the events.
+    ///
+    /// The queue is durable, is deleted after its last consumer goes away
+    /// and expires if it has no consumers for 30 minutes.
+    pub async fn declare_trace_consumer_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
+        let args = QueueArgumentsBuilder::new()
+            .expires(utils::TRACE_QUEUE_EXPIRES)
+            .build();
+        let params = QueueParams::new(name, QueueType::Classic, true, true, args);
+        self.declare_queue(virtual_host, &params).await?;
+        // "publish.{exchange}" and "deliver.{queue}" routing keys
+        self.bind_queue(virtual_host, name, "amq.rabbitmq.trace", Some("#"), None)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bind_queue(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+        exchange: &str,
+        routing_key: Option<&str>,
+        arguments: XArguments,
+    ) -> Result<()> {
+        self.post_queue_binding(virtual_host, queue, exchange, routing_key, arguments)
+            .await?;
+        Ok(())
+    }
+
+    /// Binds queues to exchanges in the given virtual host, one binding at a time.
+    ///
+    /// Failing to create one binding does not stop the others from being created.
+    /// The result for each binding is in the same position as its specification and,
+    /// on success, is the binding's properties key, which identifies it among the bindings
+    /// between the same exchange and queue (see [`responses::BindingInfo::properties_key`]).
+    /// The key is `None` if the binding was created but the response did not include
+    /// a `Location` header to read it from.
+    pub async fn bind_queues(
+        &self,
+        virtual_host: &str,
+        bindings: &[BindingSpec<'_>],
+    ) -> Vec<Result<Option<String>>> {
+        let mut results = Vec::with_capacity(bindings.len());
+        for b in bindings {
+            let result = self
+                .post_queue_binding(
+                    virtual_host,
+                    b.queue,
+                    b.exchange,
+                    b.routing_key,
+                    b.arguments.clone(),
+                )
+                .await
+                .map(|response| utils::binding_properties_key(response.headers()));
+            results.push(result);
+        }
+        results
+    }
+
+    pub async fn bind_exchange(
+        &self,
+        virtual_host: &str,
+        destination: &str,
+        source: &str,
+        routing_key: Option<&str>,
+        arguments: XArguments,
+    ) -> Result<()> {
+        let mut body = Map::<String, Value>::new();
+        if let Some(rk) = routing_key {
+            body.insert("routing_key".to_owned(), json!(rk));
+        }
+        if let Some(args) = arguments {
+            body.insert("arguments".to_owned(), json!(args));
+        }
+
+        let path = format!(
+            "bindings/{}/e/{}/e/{}",
+            percent_encode(virtual_host),
+            percent_encode(source),
+            percent_encode(destination)
+        );
+        let response = self.http_post(&path, &body).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn delete_vhost(&self, virtual_host: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!("vhosts/{}", percent_encode(virtual_host)))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn delete_user(&self, username: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!("users/{}", percent_encode(username)))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn clear_permissions(&self, virtual_host: &str, username: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "permissions/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(username)
+            ))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn delete_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "queues/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(name)
+            ))
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Deletes a queue (if it exists) and declares it again using `params`.
+    /// This is a deliberate reset: messages, consumers and bindings are not preserved.
+    pub async fn recreate_queue(&self, virtual_host: &str, params: &QueueParams<'_>) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "queues/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(params.name)
+            ))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        self.declare_queue(virtual_host, params).await
+    }
+
+    /// Like [`Client::recreate_queue`] but captures the queue's bindings
+    /// (except for the implicit default exchange binding) before deleting it
+    /// and restores them after the queue is declared again.
+    pub async fn recreate_queue_preserving_bindings(
+        &self,
+        virtual_host: &str,
+        params: &QueueParams<'_>,
+    ) -> Result<()> {
+        let bindings = match self
+            .list_queue_bindings_excluding_default(virtual_host, params.name)
+            .await
+        {
+            Ok(bs) => bs,
+            Err(Error::ClientErrorResponse(404, _)) => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        self.recreate_queue(virtual_host, params).await?;
+
+        for b in bindings {
+            let arguments = if b.arguments.is_empty() {
+                None
+            } else {
+                Some(b.arguments)
+            };
+            self.bind_queue(
+                virtual_host,
+                params.name,
+                &b.source,
+                Some(&b.routing_key),
+                arguments,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete_exchange(&self, virtual_host: &str, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "exchanges/{}/{}",
+                percent_encode(virtual_host),
+                percent_encode(name)
+            ))
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Finds the first binding between `source` and `destination` that satisfies `predicate`.
+    /// Use [`BindingInfo::has_equivalent_arguments`] in the predicate to match
+    /// headers exchange bindings by their arguments.
+    pub async fn find_binding<P>(
+        &self,
+        virtual_host: &str,
+        source: &str,
+        destination: &str,
+        destination_type: BindingDestinationType,
+        predicate: &P,
+    ) -> Result<Option<BindingInfo>>
+    where
+        P: Fn(&BindingInfo) -> bool,
+    {
+        let bindings = match destination_type {
+            BindingDestinationType::Queue => {
+                self.list_queue_bindings(virtual_host, destination).await?
+            }
+            BindingDestinationType::Exchange => {
+                self.list_exchange_bindings_with_destination(virtual_host, destination)
+                    .await?
+            }
+        };
+
+        Ok(bindings
+            .into_iter()
+            .find(|b| b.source == source && predicate(b)))
+    }
+
+    pub async fn delete_binding(
+        &self,
+        virtual_host: &str,
+        source: &str,
+        destination: &str,
+        destination_type: BindingDestinationType,
+        routing_key: &str,
+        arguments: XArguments,
+    ) -> Result<HttpClientResponse> {
+        // no arguments match bindings with an empty argument map
+        let args = arguments.unwrap_or_default();
+
+        // to delete a binding, we need properties, that we can get from the server
+        // so we search for the binding before deleting it
+        let bindings = match destination_type {
+            BindingDestinationType::Queue => {
+                self.list_queue_bindings(virtual_host, destination).await?
+            }
+            BindingDestinationType::Exchange => {
+                self.list_exchange_bindings_with_destination(virtual_host, destination)
+                    .await?
+            }
+        };
+
+        let bs: Vec<&BindingInfo> = bindings
+            .iter()
+            .filter(|b| {
+                b.source == source
+                    && b.routing_key == routing_key
+                    && b.has_equivalent_arguments(&args)
+            })
+            .collect();
+        match bs.len() {
+            0 => Err(Error::NotFound()),
+            1 => {
+                let first_key = bs.first().unwrap().properties_key.as_str();
+                let response = self
+                    .http_delete(&format!(
+                        // /api/bindings/vhost/e/exchange/[eq]/destination/props
+                        "bindings/{}/e/{}/{}/{}/{}",
+                        percent_encode(virtual_host),
+                        percent_encode(source),
+                        destination_type.path_appreviation(),
+                        percent_encode(destination),
+                        percent_encode(first_key),
+                    ))
+                    .await?;
+                self.ok_or_status_code_error(response)
+            }
+            _ => Err(Error::ManyMatchingBindings()),
+        }
+    }
+
+    /// Deletes all bindings between `exchange` and `queue`, regardless of
+    /// their routing keys and arguments. Returns the number of deleted bindings,
+    /// which does not include the ones that were deleted by someone else in the meantime.
+    pub async fn unbind_queue_from_exchange(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+        exchange: &str,
+    ) -> Result<usize> {
+        let bindings = self.list_queue_bindings(virtual_host, queue).await?;
+        let mut deleted = 0;
+        for b in bindings.iter().filter(|b| b.source == exchange) {
+            let response = self
+                .http_delete(&format!(
+                    // /api/bindings/vhost/e/exchange/q/queue/props
+                    "bindings/{}/e/{}/q/{}/{}",
+                    percent_encode(virtual_host),
+                    percent_encode(exchange),
+                    percent_encode(queue),
+                    percent_encode(&b.properties_key),
+                ))
+                .await?;
+            let response2 = self.ok_or_status_code_error_except_404(response)?;
+            // the binding may have been deleted concurrently
+            if response2.status() != StatusCode::NOT_FOUND {
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
+    pub async fn purge_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "queues/{}/{}/contents",
+                percent_encode(virtual_host),
+                percent_encode(name)
+            ))
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Publishes a message to an exchange. Returns `true` if the message was routed
+    /// to at least one queue.
+    ///
+    /// The message is published on behalf of the authenticated user by the management plugin,
+    /// so it does not originate from any listed client connection. Use
+    /// [`MessageProperties::app_id`] to attribute it to an application.
+    ///
+    /// RabbitMQ rejects messages whose `user_id` property does not match the name
+    /// of the publishing user unless the user has the `impersonator` tag. Such properties
+    /// are rejected with [`Error::UserIdMismatch`] before the message is published.
+    ///
+    /// This is meant for testing and troubleshooting, not for publishing at a high rate.
+    pub async fn publish_message(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &str,
+        properties: &MessageProperties<'_>,
+    ) -> Result<bool> {
+        if let Some(user_id) = properties.user_id {
+            if user_id != self.username && !self.is_impersonator().await? {
+                return Err(Error::UserIdMismatch {
+                    user_id: user_id.to_owned(),
+                    username: self.username.to_owned(),
+                });
+            }
+        }
+
+        let body = json!({
+            "properties": properties,
+            "routing_key": routing_key,
+            "payload": payload,
+            "payload_encoding": "string",
+        });
+        let path = format!(
+            "exchanges/{}/{}/publish",
+            percent_encode(virtual_host),
+            percent_encode(exchange)
+        );
+        let response = self.http_post(&path, &body).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        let result = self
+            .deserialize_response::<PublishResult>(response2)
+            .await?;
+        Ok(result.routed)
+    }
+
+    pub async fn list_runtime_parameters(&self) -> Result<Vec<responses::RuntimeParameter>> {
+        let response = self.http_get("parameters").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::RuntimeParameter>(response2)
+            .await
+    }
+
+    pub async fn list_runtime_parameters_of_component(
+        &self,
+        component: &str,
+    ) -> Result<Vec<responses::RuntimeParameter>> {
+        let path = format!("parameters/{}", percent_encode(component));
+        let response = self.http_get(&path).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::RuntimeParameter>(response2)
+            .await
+    }
+
+    pub async fn list_runtime_parameters_of_component_in(
+        &self,
+        component: &str,
+        vhost: &str,
+    ) -> Result<Vec<responses::RuntimeParameter>> {
+        let path = format!(
+            "parameters/{}/{}",
+            percent_encode(component),
+            percent_encode(vhost)
+        );
+        let response = self.http_get(&path).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::RuntimeParameter>(response2)
+            .await
+    }
+
+    /// Returns the distinct, sorted set of runtime parameter components in use,
+    /// e.g. "federation-upstream", "shovel" or "vhost-limits".
+    pub async fn list_runtime_parameter_components(&self) -> Result<Vec<String>> {
+        let params = self.list_runtime_parameters().await?;
+        let components: BTreeSet<String> = params.into_iter().map(|p| p.component).collect();
+        Ok(components.into_iter().collect())
+    }
+
+    pub async fn get_runtime_parameter(
+        &self,
+        component: &str,
+        vhost: &str,
+        name: &str,
+    ) -> Result<responses::RuntimeParameter> {
+        let path = format!(
+            "parameters/{}/{}/{}",
+            percent_encode(component),
+            percent_encode(vhost),
+            percent_encode(name)
+        );
+        let response = self.http_get(&path).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::RuntimeParameter>(response2)
+            .await
+    }
+
+    pub async fn upsert_runtime_parameter(&self, param: &RuntimeParameterDefinition) -> Result<()> {
+        let path = format!(
+            "parameters/{}/{}/{}",
+            percent_encode(&param.component),
+            percent_encode(&param.vhost),
+            percent_encode(&param.name)
+        );
+        let response = self.http_put(&path, &param).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn clear_runtime_parameter(
+        &self,
+        component: &str,
+        vhost: &str,
+        name: &str,
+    ) -> Result<()> {
+        let path = format!(
+            "parameters/{}/{}/{}",
+            percent_encode(component),
+            percent_encode(vhost),
+            percent_encode(name)
+        );
+        let response = self.http_delete(&path).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Declares a dynamic shovel, which is stored as a runtime parameter of the `shovel` component.
+    pub async fn declare_shovel(&self, params: &ShovelParams<'_>) -> Result<()> {
+        self.upsert_runtime_parameter(&params.into()).await
+    }
+
+    pub async fn delete_shovel(&self, vhost: &str, name: &str) -> Result<()> {
+        self.clear_runtime_parameter("shovel", vhost, name).await
+    }
+
+    /// Lists the status of all shovels, both dynamic and static.
+    /// Requires the `rabbitmq_shovel_management` plugin.
+    pub async fn list_shovels(&self) -> Result<Vec<responses::ShovelStatus>> {
+        let response = self.http_get("shovels").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::ShovelStatus>(response2)
+            .await
+    }
+
+    /// Moves the messages that are currently in `source_queue` to `destination_queue`
+    /// in the same virtual host, using a temporary dynamic shovel that deletes itself
+    /// once the source queue is drained. Requires the `rabbitmq_shovel`
+    /// and `rabbitmq_shovel_management` plugins.
+    ///
+    /// Waits for the shovel to finish for up to `timeout`, then deletes it whether it finished or not.
+    /// See [`responses::ShovelTransfer`] for the delivery guarantees.
+    pub async fn shovel_messages_once(
+        &self,
+        vhost: &str,
+        source_queue: &str,
+        destination_queue: &str,
+        timeout: Duration,
+    ) -> Result<responses::ShovelTransfer> {
+        let source = self.get_queue_info(vhost, source_queue).await?;
+        let name = utils::one_off_shovel_name(source_queue);
+        let uri = utils::local_amqp_uri(vhost);
+        let mut params =
+            ShovelParams::queue_to_queue(vhost, &name, &uri, source_queue, &uri, destination_queue);
+        params.delete_after = ShovelDeleteAfter::QueueLength;
+        self.declare_shovel(&params).await?;
+
+        let started_at = Instant::now();
+        let mut transfer = responses::ShovelTransfer {
+            shovel_name: name.clone(),
+            source_message_count: source.message_count,
+            completed: false,
+            termination_reason: None,
+        };
+        loop {
+            let status = self
+                .list_shovels()
+                .await?
+                .into_iter()
+                .find(|s| s.name == name && s.vhost.as_deref() == Some(vhost));
+            let terminated = match status {
+                Some(s) if s.state == "terminated" => Some(s.reason),
+                Some(_) => None,
+                // not listed before it starts or after it is done
+                None => Some(None),
+            };
+            if let Some(reason) = terminated {
+                // the shovel deletes its runtime parameter once the source queue is drained
+                match self.get_runtime_parameter("shovel", vhost, &name).await {
+                    Err(Error::ClientErrorResponse(404, _)) => {
+                        transfer.completed = true;
+                        break;
+                    }
+                    Err(e) => return Err(e),
+                    Ok(_) if reason.is_some() => {
+                        transfer.termination_reason = reason;
+                        break;
+                    }
+                    Ok(_) => {}
+                }
+            }
+            if started_at.elapsed() >= timeout {
+                break;
+            }
+            tokio::time::sleep(utils::SHOVEL_POLL_INTERVAL).await;
+        }
+
+        match self.delete_shovel(vhost, &name).await {
+            Ok(()) | Err(Error::ClientErrorResponse(404, _)) => Ok(transfer),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn clear_all_runtime_parameters(&self) -> Result<()> {
+        let params = self.list_runtime_parameters().await?;
+        for rp in params {
+            self.clear_runtime_parameter(&rp.component, &rp.vhost, &rp.name)
+                .await?
+        }
+        Ok(())
+    }
+
+    pub async fn clear_all_runtime_parameters_of_component(&self, component: &str) -> Result<()> {
+        let params = self.list_runtime_parameters_of_component(component).await?;
+        for rp in params {
+            self.clear_runtime_parameter(&rp.component, &rp.vhost, &rp.name)
+                .await?
+        }
+        Ok(())
+    }
+
+    pub async fn set_user_limit(
+        &self,
+        username: &str,
+        limit: EnforcedLimitParams<UserLimitTarget>,
+    ) -> Result<()> {
+        let path = format!("user-limits/{}/{}", username, String::from(limit.kind));
+
+        let mut body = Map::<String, Value>::new();
+        body.insert("value".to_owned(), json!(limit.value));
+
+        let response = self.http_put(&path, &body).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn clear_user_limit(&self, username: &str, kind: UserLimitTarget) -> Result<()> {
+        let path = format!(
+            "user-limits/{}/{}",
+            percent_encode(username),
+            String::from(kind)
+        );
+
+        let response = self.http_delete(&path).await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn list_all_user_limits(&self) -> Result<Vec<responses::UserLimits>> {
+        let response = self.http_get("user-limits").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::UserLimits>(response2)
+            .await
+    }
+
+    pub async fn list_user_limits(&self, username: &str) -> Result<Vec<responses::UserLimits>> {
+        let path = format!("user-limits/{}", username);
+        let response = self.http_get(&path).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::UserLimits>(response2)
+            .await
+    }
+
+    /// Clears every limit currently set for the given user.
+    pub async fn clear_all_user_limits(&self, username: &str) -> Result<()> {
+        for user_limits in self.list_user_limits(username).await? {
+            for kind in user_limits.limits.keys() {
+                self.clear_user_limit(username, UserLimitTarget::from(kind.as_str()))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn set_vhost_limit(
+        &self,
+        vhost: &str,
+        limit: EnforcedLimitParams<VirtualHostLimitTarget>,
+    ) -> Result<()> {
+        let path = format!(
+            "vhost-limits/{}/{}",
+            percent_encode(vhost),
+            String::from(limit.kind)
+        );
+
+        let mut body = Map::<String, Value>::new();
+        body.insert("value".to_owned(), json!(limit.value));
+
+        let response = self.http_put(&path, &body).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Sets several limits of a virtual host at once, replacing all of its current limits:
+    /// limits that are not in `limits` are cleared.
+    ///
+    /// Virtual host limits are stored as the `limits` runtime parameter of the `vhost-limits`
+    /// component, which is what this function updates. Limits set this way can be listed and
+    /// cleared with [`Client::list_vhost_limits`], [`Client::clear_vhost_limit`] and
+    /// [`Client::clear_all_vhost_limits`], just like the ones set with [`Client::set_vhost_limit`].
+    pub async fn set_vhost_limits(
+        &self,
+        vhost: &str,
+        limits: Vec<EnforcedLimitParams<VirtualHostLimitTarget>>,
+    ) -> Result<()> {
+        let mut value = Map::<String, Value>::new();
+        for limit in limits {
+            value.insert(String::from(limit.kind), json!(limit.value));
+        }
+
+        let param = RuntimeParameterDefinition {
+            name: "limits".to_owned(),
+            vhost: vhost.to_owned(),
+            component: "vhost-limits".to_owned(),
+            value,
+        };
+        self.upsert_runtime_parameter(&param).await
+    }
+
+    pub async fn clear_vhost_limit(&self, vhost: &str, kind: VirtualHostLimitTarget) -> Result<()> {
+        let path = format!(
+            "vhost-limits/{}/{}",
+            percent_encode(vhost),
+            String::from(kind)
+        );
+
+        let response = self.http_delete(&path).await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn list_all_vhost_limits(&self) -> Result<Vec<responses::VirtualHostLimits>> {
+        let response = self.http_get("vhost-limits").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::VirtualHostLimits>(response2)
+            .await
+    }
+
+    pub async fn list_vhost_limits(
+        &self,
+        vhost: &str,
+    ) -> Result<Vec<responses::VirtualHostLimits>> {
+        let path = format!("vhost-limits/{}", percent_encode(vhost));
+        let response = self.http_get(&path).await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::VirtualHostLimits>(response2)
+            .await
+    }
+
+    /// Clears every limit currently set for the given virtual host.
+    pub async fn clear_all_vhost_limits(&self, vhost: &str) -> Result<()> {
+        for vhost_limits in self.list_vhost_limits(vhost).await? {
+            for kind in vhost_limits.limits.keys() {
+                self.clear_vhost_limit(vhost, VirtualHostLimitTarget::from(kind.as_str()))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the `max-connections` limit of a virtual host along with the connections
+    /// counted against it, listed per node.
+    ///
+    /// The HTTP API does not report whether connection tracking is enabled on a node.
+    /// A connection count over the limit (see [`responses::ConnectionTracking::is_over_limit`])
+    /// is the best available sign that it is not, which is a common reason for a limit to not be enforced.
+    pub async fn list_connection_tracking(
+        &self,
+        vhost: &str,
+    ) -> Result<responses::ConnectionTracking> {
+        let limits = self.list_vhost_limits(vhost).await?;
+        let connections = self.list_connections_in(vhost).await?;
+        Ok(utils::connection_tracking(vhost, limits, connections))
+    }
+
+    pub async fn list_feature_flags(&self) -> Result<Vec<responses::FeatureFlag>> {
+        let response = self.http_get("feature-flags").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::FeatureFlag>(response2)
+            .await
+    }
+
+    /// Lists the feature flags that are safe to enable in production: stable and required ones.
+    pub async fn list_stable_feature_flags(&self) -> Result<Vec<responses::FeatureFlag>> {
+        let mut flags = self.list_feature_flags().await?;
+        flags.retain(|ff| {
+            matches!(
+                ff.stability,
+                FeatureFlagStability::Stable | FeatureFlagStability::Required
+            )
+        });
+        Ok(flags)
+    }
+
+    pub async fn list_experimental_feature_flags(&self) -> Result<Vec<responses::FeatureFlag>> {
+        let mut flags = self.list_feature_flags().await?;
+        flags.retain(|ff| ff.stability == FeatureFlagStability::Experimental);
+        Ok(flags)
+    }
+
+    /// Enables a feature flag. Enabling a flag that is already enabled is a no-op.
+    ///
+    /// Experimental flags cannot be disabled once enabled, so they are refused with an
+    /// [`Error::ExperimentalFeatureFlag`], see [`Client::enable_experimental_feature_flag`].
+    ///
+    /// If RabbitMQ refuses to enable the flag, for example, because some nodes
+    /// do not support it, returns an [`Error::FeatureFlagNotEnabled`] with the reason it reported.
+    pub async fn enable_feature_flag(&self, name: &str) -> Result<()> {
+        let flags = self.list_feature_flags().await?;
+        if flags
+            .iter()
+            .any(|ff| ff.name == name && ff.stability == FeatureFlagStability::Experimental)
+        {
+            return Err(Error::ExperimentalFeatureFlag {
+                name: name.to_owned(),
+            });
+        }
+        self.enable_experimental_feature_flag(name).await
+    }
+
+    /// Enables a feature flag regardless of its stability, including experimental ones,
+    /// which cannot be disabled again.
+    pub async fn enable_experimental_feature_flag(&self, name: &str) -> Result<()> {
+        let path = format!("feature-flags/{}/enable", percent_encode(name));
+        let response = self.http_put(&path, &Map::<String, Value>::new()).await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        if status.as_u16() == 401 || status.as_u16() == 403 {
+            return Err(Self::client_error(response));
+        }
+
+        let reason = match response.json::<responses::ErrorDetails>().await {
+            Ok(details) if !details.reason.is_empty() => details.reason,
+            _ => status.to_string(),
+        };
+        Err(Error::FeatureFlagNotEnabled {
+            name: name.to_owned(),
+            reason,
+        })
+    }
+
+    pub async fn list_deprecated_features(&self) -> Result<Vec<responses::DeprecatedFeature>> {
+        let response = self.http_get("deprecated-features").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::DeprecatedFeature>(response2)
+            .await
+    }
+
+    /// Lists the deprecated features that are currently in use, which should be
+    /// migrated away from before upgrading to a version where they are no longer permitted.
+    pub async fn list_deprecated_features_in_use(
+        &self,
+    ) -> Result<Vec<responses::DeprecatedFeature>> {
+        let response = self.http_get("deprecated-features/used").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::DeprecatedFeature>(response2)
+            .await
+    }
+
+    pub async fn get_cluster_name(&self) -> Result<responses::ClusterIdentity> {
+        let response = self.http_get("cluster-name").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::ClusterIdentity>(response2)
+            .await
+    }
+
+    pub async fn set_cluster_name(&self, new_name: &str) -> Result<()> {
+        let mut map = HashMap::new();
+        map.insert("name", new_name);
+
+        let response = self.http_put("cluster-name", &map).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn list_global_parameters(&self) -> Result<Vec<responses::GlobalRuntimeParameter>> {
+        let response = self.http_get("global-parameters").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::GlobalRuntimeParameter>(response2)
+            .await
+    }
+
+    pub async fn get_global_parameter(
+        &self,
+        name: &str,
+    ) -> Result<responses::GlobalRuntimeParameter> {
+        let response = self
+            .http_get(&format!("global-parameters/{}", percent_encode(name)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::GlobalRuntimeParameter>(response2)
+            .await
+    }
+
+    pub async fn set_global_parameter(&self, name: &str, value: Value) -> Result<()> {
+        let body = json!({
+            "name": name,
+            "value": value,
+        });
+        let response = self
+            .http_put(
+                &format!("global-parameters/{}", percent_encode(name)),
+                &body,
+            )
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn clear_global_parameter(&self, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!("global-parameters/{}", percent_encode(name)))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    /// Returns the cluster tags, such as `region` or `environment`, which are stored
+    /// in the `cluster_tags` global parameter. They are initially set from the `cluster_tags`
+    /// section of the configuration file.
+    pub async fn get_cluster_tags(&self) -> Result<responses::ClusterTags> {
+        match self
+            .get_global_parameter(utils::CLUSTER_TAGS_PARAMETER)
+            .await
+        {
+            Ok(param) => Ok(utils::cluster_tags(param.value)),
+            Err(Error::ClientErrorResponse(404, _)) => Ok(responses::ClusterTags::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replaces all cluster tags.
+    ///
+    /// Nodes set the tags from their configuration file when they boot,
+    /// so tags set this way should also be added there to survive a restart.
+    pub async fn set_cluster_tags(&self, tags: responses::ClusterTags) -> Result<()> {
+        self.set_global_parameter(utils::CLUSTER_TAGS_PARAMETER, Value::Object(tags))
+            .await
+    }
+
+    /// Sets a single cluster tag, such as the team that owns the cluster
+    /// or an ongoing maintenance window, and keeps all other tags.
+    ///
+    /// Concurrent updates of different tags can overwrite each other.
+    pub async fn set_cluster_metadata(&self, key: &str, value: &str) -> Result<()> {
+        let mut tags = self.get_cluster_tags().await?;
+        tags.insert(key.to_owned(), json!(value));
+        self.set_cluster_tags(tags).await
+    }
+
+    /// Returns cluster-wide information: versions, the cluster name, object totals
+    /// and, when message stats are collected, aggregate message counts and rates.
+    pub async fn get_overview(&self) -> Result<responses::Overview> {
+        let response = self.http_get("overview").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Overview>(response2)
+            .await
+    }
+
+    /// Returns the sample retention policies that govern the granularity and age
+    /// of the data that message rates (e.g. in `message_stats`) are computed from.
+    pub async fn get_sample_retention_policies(
+        &self,
+    ) -> Result<responses::SampleRetentionPolicies> {
+        let response = self
+            .http_get_with_query("overview", &[("columns", "sample_retention_policies")])
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        let overview = self
+            .deserialize_response::<OverviewSampleRetentionPolicies>(response2)
+            .await?;
+        Ok(overview.sample_retention_policies)
+    }
+
+    pub async fn get_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
+        let response = self
+            .http_get(&format!(
+                "policies/{}/{}",
+                percent_encode(vhost),
+                percent_encode(name)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Policy>(response2)
+            .await
+    }
+
+    pub async fn list_policies(&self) -> Result<Vec<responses::Policy>> {
+        let response = self.http_get("policies").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Policy>(response2)
+            .await
+    }
+
+    pub async fn list_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
+        let response = self
+            .http_get(&format!("policies/{}", percent_encode(vhost)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Policy>(response2)
+            .await
+    }
+
+    /// Lists policies in a virtual host in the order they are evaluated:
+    /// highest priority first, ties broken by name.
+    pub async fn list_policies_in_priority_order(
+        &self,
+        vhost: &str,
+    ) -> Result<Vec<responses::Policy>> {
+        let mut policies = self.list_policies_in(vhost).await?;
+        policies.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Ok(policies)
+    }
+
+    pub async fn declare_policy(&self, params: &PolicyParams<'_>) -> Result<()> {
+        let response = self
+            .http_put(
+                &format!(
+                    "policies/{}/{}",
+                    percent_encode(params.vhost),
+                    percent_encode(params.name)
+                ),
+                params,
+            )
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    pub async fn delete_policy(&self, vhost: &str, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "policies/{}/{}",
+                percent_encode(vhost),
+                percent_encode(name)
+            ))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    /// Deletes all policies in a virtual host, for example, before re-applying them
+    /// from a definitions file. Returns the number of deleted policies.
+    pub async fn clear_all_policies_in(&self, vhost: &str) -> Result<usize> {
+        let policies = self.list_policies_in(vhost).await?;
+        for p in &policies {
+            self.delete_policy(vhost, &p.name).await?;
+        }
+        Ok(policies.len())
+    }
+
+    /// Returns the policy that is currently applied to the given exchange, if any.
+    ///
+    /// This is always a user policy: operator policies only apply to queues and streams.
+    pub async fn effective_exchange_policy(
+        &self,
+        vhost: &str,
+        exchange: &str,
+    ) -> Result<Option<responses::Policy>> {
+        let x = self.get_exchange_info(vhost, exchange).await?;
+        match x.policy {
+            Some(name) => self.get_policy(vhost, &name).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn get_operator_policy(&self, vhost: &str, name: &str) -> Result<responses::Policy> {
+        let response = self
+            .http_get(&format!(
+                "operator-policies/{}/{}",
+                percent_encode(vhost),
+                percent_encode(name)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Policy>(response2)
+            .await
+    }
+
+    pub async fn list_operator_policies(&self) -> Result<Vec<responses::Policy>> {
+        let response = self.http_get("operator-policies").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Policy>(response2)
+            .await
+    }
+
+    pub async fn list_operator_policies_in(&self, vhost: &str) -> Result<Vec<responses::Policy>> {
+        let response = self
+            .http_get(&format!("operator-policies/{}", percent_encode(vhost)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Policy>(response2)
+            .await
+    }
+
+    pub async fn declare_operator_policy(&self, params: &PolicyParams<'_>) -> Result<()> {
+        let response = self
+            .http_put(
+                &format!(
+                    "operator-policies/{}/{}",
+                    percent_encode(params.vhost),
+                    percent_encode(params.name)
+                ),
+                params,
+            )
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    pub async fn delete_operator_policy(&self, vhost: &str, name: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!(
+                "operator-policies/{}/{}",
+                percent_encode(vhost),
+                percent_encode(name)
+            ))
+            .await?;
+        self.ok_or_status_code_error_except_404(response)?;
+        Ok(())
+    }
+
+    /// Deletes all operator policies in a virtual host.
+    /// Returns the number of deleted operator policies.
+    pub async fn clear_all_operator_policies_in(&self, vhost: &str) -> Result<usize> {
+        let policies = self.list_operator_policies_in(vhost).await?;
+        for p in &policies {
+            self.delete_operator_policy(vhost, &p.name).await?;
+        }
+        Ok(policies.len())
+    }
+
+    pub async fn list_permissions(&self) -> Result<Vec<responses::Permissions>> {
+        let response = self.http_get("permissions").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Permissions>(response2)
+            .await
+    }
+
+    pub async fn list_permissions_in(&self, vhost: &str) -> Result<Vec<responses::Permissions>> {
+        let response = self
+            .http_get(&format!("vhosts/{}/permissions", percent_encode(vhost)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Permissions>(response2)
+            .await
+    }
+
+    pub async fn list_permissions_of(&self, user: &str) -> Result<Vec<responses::Permissions>> {
+        let response = self
+            .http_get(&format!("users/{}/permissions", percent_encode(user)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::Permissions>(response2)
+            .await
+    }
+
+    pub async fn get_permissions(&self, vhost: &str, user: &str) -> Result<responses::Permissions> {
+        let response = self
+            .http_get(&format!(
+                "permissions/{}/{}",
+                percent_encode(vhost),
+                percent_encode(user)
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Permissions>(response2)
+            .await
+    }
+
+    pub async fn list_topic_permissions_of(
+        &self,
+        user: &str,
+    ) -> Result<Vec<responses::TopicPermissions>> {
+        let response = self
+            .http_get(&format!("users/{}/topic-permissions", percent_encode(user)))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::TopicPermissions>(response2)
+            .await
+    }
+
+    /// Collects a user, its permissions, topic permissions and limits into a single value.
+    pub async fn export_user(&self, username: &str) -> Result<responses::UserExport> {
+        let user = self.get_user(username).await?;
+        let permissions = self.list_permissions_of(username).await?;
+        let topic_permissions = self.list_topic_permissions_of(username).await?;
+        let limits = self
+            .list_user_limits(username)
+            .await?
+            .into_iter()
+            .flat_map(|ul| ul.limits)
+            .collect();
+
+        Ok(responses::UserExport {
+            user,
+            permissions,
+            topic_permissions,
+            limits,
+        })
+    }
+
+    //
+    // Statistics
+    //
+
+    /// Resets the management statistics database on all cluster nodes,
+    /// for example, to get clean rate baselines after a benchmark.
+    /// Requires the `administrator` tag.
+    pub async fn reset_stats(&self) -> Result<()> {
+        let response = self.http_delete("reset").await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    /// Resets the management statistics database on a single node.
+    pub async fn reset_node_stats(&self, node: &str) -> Result<()> {
+        let response = self
+            .http_delete(&format!("reset/{}", percent_encode(node)))
+            .await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    //
+    // Rebalancing
+    //
+
+    pub async fn rebalance_queue_leaders(&self) -> Result<()> {
+        let m: HashMap<String, Value> = HashMap::new();
+        self.http_post("rebalance/queues", &m).await?;
+
+        Ok(())
+    }
+
+    //
+    // Definitions
+
+    pub async fn export_definitions(&self) -> Result<String> {
+        let response = self.http_get("definitions").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        response2.text().await.map_err(Error::from)
+    }
+
+    /// Exports cluster-wide definitions and writes the response body to `writer`
+    /// chunk by chunk as it arrives, without buffering it in memory.
+    /// Returns the number of bytes written.
+    pub async fn export_definitions_to_writer<W>(&self, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let response = self.http_get("definitions").await?;
+        let mut response2 = self.ok_or_status_code_error(response)?;
+        let mut written = 0;
+        while let Some(chunk) = response2.chunk().await? {
+            writer.write_all(&chunk).await.map_err(Error::WriteError)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(Error::WriteError)?;
+        Ok(written)
+    }
+
+    /// Exports cluster-wide definitions as [`responses::Definitions`].
+    /// Use [`responses::Definitions::to_json`] to turn them back into a document
+    /// that can be passed to [`Client::import_definitions`].
+    pub async fn export_definitions_as_data(&self) -> Result<responses::Definitions> {
+        let response = self.http_get("definitions").await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_response::<responses::Definitions>(response2)
+            .await
+    }
+
+    /// Exports cluster-wide definitions and returns only the policies.
+    pub async fn export_policies(&self) -> Result<Vec<responses::Policy>> {
+        self.export_definitions_as_data()
+            .await
+            .map(|defs| defs.policies)
+    }
+
+    /// Exports cluster-wide definitions and returns only the runtime parameters.
+    pub async fn export_runtime_parameters(&self) -> Result<Vec<responses::RuntimeParameter>> {
+        self.export_definitions_as_data()
+            .await
+            .map(|defs| defs.parameters)
+    }
+
+    /// Exports cluster-wide definitions and returns only the queues.
+    pub async fn export_queues(&self) -> Result<Vec<responses::QueueDefinition>> {
+        self.export_definitions_as_data()
+            .await
+            .map(|defs| defs.queues)
+    }
+
+    /// Exports cluster-wide definitions and returns only the exchanges.
+    pub async fn export_exchanges(&self) -> Result<Vec<responses::ExchangeDefinition>> {
+        self.export_definitions_as_data()
+            .await
+            .map(|defs| defs.exchanges)
+    }
+
+    /// Exports cluster-wide definitions and returns only the bindings.
+    pub async fn export_bindings(&self) -> Result<Vec<responses::BindingDefinition>> {
+        self.export_definitions_as_data()
+            .await
+            .map(|defs| defs.bindings)
+    }
+
+    /// Compares the given definitions against the ones currently in the cluster and reports
+    /// what an import would create, update or leave unchanged, and which existing queues
+    /// and exchanges it would conflict with. Nothing is applied.
+    pub async fn plan_import(
+        &self,
+        definitions: &responses::Definitions,
+    ) -> Result<responses::ImportPlan> {
+        let current = self.export_definitions_as_data().await?;
+        Ok(responses::ImportPlan::compute(definitions, &current))
+    }
+
+    pub async fn import_definitions(&self, definitions: Value) -> Result<()> {
+        let response = self.http_post("definitions", &definitions).await?;
+        self.ok_or_status_code_error(response)?;
+        Ok(())
+    }
+
+    //
+    // Health Checks
+    //
+
+    pub async fn health_check_cluster_wide_alarms(&self) -> Result<()> {
+        self.health_check_alarms("health/checks/alarms").await
+    }
+
+    pub async fn health_check_local_alarms(&self) -> Result<()> {
+        self.health_check_alarms("health/checks/local-alarms").await
+    }
+
+    pub async fn health_check_if_node_is_quorum_critical(&self) -> Result<()> {
+        let response = self
+            .http_get_once("health/checks/node-is-quorum-critical")
+            .await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::QuorumCriticalityCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::NodeIsQuorumCritical(failure_details),
+        ))
+    }
+
+    /// Checks that all virtual hosts are running on the node that serves the request.
+    /// Virtual hosts can fail to start, for example, after their data was corrupted,
+    /// so this is worth checking after every node restart.
+    pub async fn health_check_virtual_hosts(&self) -> Result<()> {
+        let response = self.http_get_once("health/checks/virtual-hosts").await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::VirtualHostsCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::VirtualHostsDown(failure_details),
+        ))
+    }
+
+    /// Checks that the node that serves the request is running and accepts client connections,
+    /// for example, that it is not in maintenance mode. Useful during rolling restarts.
+    pub async fn health_check_node_is_in_service(&self) -> Result<()> {
+        let response = self.http_get_once("health/checks/is-in-service").await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::NodeInServiceCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::NodeNotInService(failure_details),
+        ))
+    }
+
+    /// Checks that the node that serves the request has an active listener
+    /// for the given protocol, such as `amqp`, `amqp/ssl`, `mqtt` or `stream`.
+    pub async fn health_check_protocol_listener(&self, protocol: &str) -> Result<()> {
+        let path = format!(
+            "health/checks/protocol-listener/{}",
+            percent_encode(protocol)
+        );
+        let response = self.http_get_once(&path).await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::ProtocolListenerCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::NoActiveProtocolListener(failure_details),
+        ))
+    }
+
+    /// Checks that the node that serves the request has an active listener on the given port.
+    pub async fn health_check_port_listener(&self, port: u16) -> Result<()> {
+        let path = format!("health/checks/port-listener/{}", port);
+        let response = self.http_get_once(&path).await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::PortListenerCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::NoActivePortListener(failure_details),
+        ))
+    }
+
+    /// Checks that none of the TLS certificates used by the listeners in the cluster
+    /// expire within the given window, for example, 30 days.
+    pub async fn health_check_certificate_expiration(
+        &self,
+        within: u32,
+        unit: TimeUnit,
+    ) -> Result<()> {
+        let path = format!("health/checks/certificate-expiration/{}/{}", within, unit);
+        let response = self.http_get_once(&path).await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::CertificateExpirationCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::CertificatesExpiring(failure_details),
+        ))
+    }
+
+    /// Declares a temporary queue in the given virtual host, publishes a message to it,
+    /// consumes it and deletes the queue. Unlike the alarm checks, this exercises
+    /// the message path end to end.
+    pub async fn health_check_aliveness(&self, virtual_host: &str) -> Result<()> {
+        let response = self
+            .http_get_once(&format!("aliveness-test/{}", percent_encode(virtual_host)))
+            .await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        let details = self
+            .deserialize_response::<responses::AlivenessCheckDetails>(response2)
+            .await?;
+        if details.status == "ok" {
+            return Ok(());
+        }
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::AlivenessCheck(details),
+        ))
+    }
+
+    /// Checks that every virtual host has fewer than `limit` queues.
+    ///
+    /// RabbitMQ does not provide a dedicated health check endpoint for this,
+    /// so the check is performed on the client side using [`Client::list_queues`].
+    pub async fn health_check_below_vhost_queue_count(&self, limit: usize) -> Result<()> {
+        let queues = self.list_queues().await?;
+        match utils::queue_count_check_details(queues, limit) {
+            None => Ok(()),
+            Some(failure_details) => Err(Error::HealthCheckFailed(
+                responses::HealthCheckFailureDetails::QueueCountLimitReached(failure_details),
+            )),
+        }
+    }
+
+    /// Fetches the queues, exchanges and bindings in the given virtual host
+    /// (or in all virtual hosts) and groups the bindings by the queues and exchanges they connect.
+    ///
+    /// The objects are fetched with three separate requests, so objects declared
+    /// or deleted in between may be missing or have dangling bindings.
+    pub async fn topology_snapshot(
+        &self,
+        vhost: Option<&str>,
+    ) -> Result<responses::TopologySnapshot> {
+        let (queues, exchanges, bindings) = match vhost {
+            Some(vh) => (
+                self.list_queues_in(vh).await?,
+                self.list_exchanges_in(vh).await?,
+                self.list_bindings_in(vh).await?,
+            ),
+            None => (
+                self.list_queues().await?,
+                self.list_exchanges().await?,
+                self.list_bindings().await?,
+            ),
+        };
+        Ok(utils::topology_snapshot(queues, exchanges, bindings))
+    }
+
+    /// Compares the queues, exchanges, bindings and policies of this cluster with those
+    /// of the `other` cluster, for example, to verify that a standby cluster matches the primary one.
+    ///
+    /// Objects in a virtual host that only exists on one side are reported as only present on that side.
+    pub async fn compare_topology(&self, other: &Client<'_>) -> Result<responses::TopologyDiff> {
+        let this_topology = self.fetch_topology().await?;
+        let other_topology = other.fetch_topology().await?;
+        Ok(utils::topology_diff(this_topology, other_topology))
+    }
+
+    //
+    // Implementation
+    //
+
+    /// Returns `true` if the authenticated user can publish messages on behalf of other users.
+    async fn is_impersonator(&self) -> Result<bool> {
+        let user = self.whoami().await?;
+        Ok(user.tags.iter().any(|t| t == "impersonator"))
+    }
+
+    async fn deserialize_response<T: DeserializeOwned>(
+        &self,
+        response: HttpClientResponse,
+    ) -> Result<T> {
+        let body = response.bytes().await?;
+        if !self.lenient_deserialization {
+            return serde_json::from_slice(&body).map_err(Error::DeserializationError);
+        }
+
+        let value = serde_json::from_slice(&body).map_err(Error::DeserializationError)?;
+        responses::deserialize_leniently(value).map_err(Error::DeserializationError)
+    }
+
+    async fn deserialize_list_response<T: DeserializeOwned>(
+        &self,
+        response: HttpClientResponse,
+    ) -> Result<Vec<T>> {
+        let body = response.bytes().await?;
+        if !self.lenient_deserialization {
+            return serde_json::from_slice(&body).map_err(Error::DeserializationError);
+        }
+
+        let value = serde_json::from_slice(&body).map_err(Error::DeserializationError)?;
+        responses::deserialize_list_leniently(value).map_err(Error::DeserializationError)
+    }
+
+    async fn post_queue_binding(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+        exchange: &str,
+        routing_key: Option<&str>,
+        arguments: XArguments,
+    ) -> Result<HttpClientResponse> {
+        let mut body = Map::<String, Value>::new();
+        if let Some(rk) = routing_key {
+            body.insert("routing_key".to_owned(), json!(rk));
+        }
+        if let Some(args) = arguments {
+            body.insert("arguments".to_owned(), json!(args));
+        }
+
+        let path = format!(
+            "bindings/{}/e/{}/q/{}",
+            percent_encode(virtual_host),
+            percent_encode(exchange),
+            percent_encode(queue)
+        );
+        let response = self.http_post(&path, &body).await?;
+        self.ok_or_status_code_error(response)
+    }
+
+    /// Returns `false` if the connection did not exist (anymore).
+    async fn close_connection_if_exists(&self, name: &str, reason: Option<&str>) -> Result<bool> {
+        let response: HttpClientResponse = match reason {
+            None => {
+                self.http_delete(&format!("connections/{}", percent_encode(name)))
+                    .await?
+            }
+            Some(value) => {
+                let mut headers = HeaderMap::new();
+                let hdr = HeaderValue::from_str(value)?;
+                headers.insert("X-Reason", hdr);
+                self.http_delete_with_headers(
+                    &format!("connections/{}", percent_encode(name)),
+                    headers,
+                )
+                .await?
+            }
+        };
+        let response2 = self.ok_or_status_code_error_except_404(response)?;
+        Ok(response2.status() != StatusCode::NOT_FOUND)
+    }
+
+    async fn fetch_topology(&self) -> Result<utils::Topology> {
+        Ok(utils::Topology {
+            vhosts: self.list_vhosts().await?,
+            queues: self.list_queues().await?,
+            exchanges: self.list_exchanges().await?,
+            bindings: self.list_bindings().await?,
+            policies: self.list_policies().await?,
+        })
+    }
+
+    async fn health_check_alarms(&self, path: &str) -> Result<()> {
+        let response = self.http_get_once(path).await?;
+        let response2 = self.ok_or_status_code_error_except_503(response)?;
+
+        if response2.status().is_success() {
+            return Ok(());
+        }
+
+        let failure_details = self
+            .deserialize_response::<responses::ClusterAlarmCheckDetails>(response2)
+            .await?;
+        Err(Error::HealthCheckFailed(
+            responses::HealthCheckFailureDetails::AlarmCheck(failure_details),
+        ))
+    }
+
+    async fn list_exchange_bindings_with_source_or_destination(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+        vertex: BindindVertex,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let response = self
+            .http_get(&format!(
+                "exchanges/{}/{}/bindings/{}",
+                percent_encode(virtual_host),
+                percent_encode(exchange),
+                vertex
+            ))
+            .await?;
+        let response2 = self.ok_or_status_code_error(response)?;
+        self.deserialize_list_response::<responses::BindingInfo>(response2)
+            .await
+    }
+
+    async fn http_get(&self, path: &str) -> Result<HttpClientResponse> {
+        let client = self.http_client()?;
+        let request = client.get(self.rooted_path(path));
+        self.send_with_retries(client, request).await
+    }
+
+    /// Like `http_get` but never retried, for health checks, which respond with a 503 when they fail.
+    async fn http_get_once(&self, path: &str) -> Result<HttpClientResponse> {
+        let client = self.http_client()?;
+        let request = client.get(self.rooted_path(path));
+        self.send(client, request).await
+    }
+
+    async fn http_get_with_query(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<HttpClientResponse> {
+        let client = self.http_client()?;
+        let request = client.get(self.rooted_path(path)).query(query);
+        self.send_with_retries(client, request).await
+    }
+
+    async fn http_put<T>(&self, path: &str, payload: &T) -> Result<HttpClientResponse>
+    where
+        T: Serialize,
+    {
+        let client = self.http_client()?;
+        let request = client.put(self.rooted_path(path)).json(&payload);
+        self.send(client, request).await
+    }
+
+    async fn http_post<T>(&self, path: &str, payload: &T) -> Result<HttpClientResponse>
+    where
+        T: Serialize,
+    {
+        let client = self.http_client()?;
+        let request = client.post(self.rooted_path(path)).json(&payload);
+        self.send(client, request).await
+    }
+
+    async fn http_delete(&self, path: &str) -> Result<HttpClientResponse> {
+        let client = self.http_client()?;
+        let request = client.delete(self.rooted_path(path));
+        self.send(client, request).await
+    }
+
+    async fn http_delete_with_headers(
+        &self,
+        path: &str,
+        headers: HeaderMap,
+    ) -> Result<HttpClientResponse> {
+        let client = self.http_client()?;
+        let request = client.delete(self.rooted_path(path)).headers(headers);
+        self.send(client, request).await
+    }
+
+    async fn send_with_retries(
+        &self,
+        client: &HttpClient,
+        request: RequestBuilder,
+    ) -> Result<HttpClientResponse> {
+        let mut delay = self.retry_base_delay;
+        for _ in 0..self.max_retries {
+            // GET requests have no body, so they can always be cloned
+            let Some(attempt) = request.try_clone() else {
+                break;
+            };
+            match self.send(client, attempt).await {
+                Ok(response) if response.status() != StatusCode::SERVICE_UNAVAILABLE => {
+                    return Ok(response)
+                }
+                Ok(_) | Err(Error::RequestError(_)) => {}
+                Err(e) => return Err(e),
+            }
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2);
+        }
+        self.send(client, request).await
+    }
+
+    async fn send(
+        &self,
+        client: &HttpClient,
+        request: RequestBuilder,
+    ) -> Result<HttpClientResponse> {
+        if self.preemptive_auth {
+            let request = request.basic_auth(self.username, Some(self.password));
+            return self.execute(client, request).await;
+        }
+
+        // request bodies are JSON documents, so the request can always be cloned
+        let retry = request.try_clone();
+        let response = self.execute(client, request).await?;
+        match retry {
+            Some(retry) if response.status() == StatusCode::UNAUTHORIZED => {
+                let retry = retry.basic_auth(self.username, Some(self.password));
+                self.execute(client, retry).await
+            }
+            _ => Ok(response),
+        }
+    }
+
+    async fn execute(
+        &self,
+        client: &HttpClient,
+        request: RequestBuilder,
+    ) -> Result<HttpClientResponse> {
+        let request = request.build()?;
+        self.inspect(&request);
+        let context = RequestContext::new(request.method(), request.url());
+        let mut response = self.ok_or_http_client_error(client.execute(request).await)?;
+        // recorded so that error responses can be attributed to their request
+        response.extensions_mut().insert(context);
+        Ok(response)
+    }
+
+    fn inspect(&self, request: &HttpRequest) {
+        if let Some(inspector) = &self.request_inspector {
+            let mut redacted =
+                HttpRequest::new(request.method().clone(), utils::redacted_url(request.url()));
+            *redacted.headers_mut() = utils::redacted_headers(request.headers());
+            inspector(&redacted);
+        }
+    }
+
+    fn ok_or_http_client_error(
+        &self,
+        result: reqwest::Result<HttpClientResponse>,
+    ) -> Result<HttpClientResponse> {
+        match result {
+            Ok(val) => Ok(val),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    fn ok_or_status_code_error(&self, response: HttpClientResponse) -> Result<HttpClientResponse> {
+        let status = response.status();
+        if status.is_client_error() {
+            return Err(Self::client_error(response));
+        }
+
+        if status.is_server_error() {
+            return Err(Error::ServerErrorResponse(status.as_u16(), response));
+        }
+
+        Ok(response)
+    }
+
+    fn ok_or_status_code_error_except_404(
+        &self,
+        response: HttpClientResponse,
+    ) -> Result<HttpClientResponse> {
+        let status = response.status();
+
+        // Do not consider 404s an error to allow for idempotent deletes
+        if status.is_client_error() && status.as_u16() != 404 {
+            return Err(Self::client_error(response));
+        }
+
+        if status.is_server_error() {
+            return Err(Error::ServerErrorResponse(status.as_u16(), response));
+        }
+
+        Ok(response)
+    }
+
+    /// Returns the HTTP client, building it on first use.
+    /// The same client (and its connection pool) is then used for all requests.
+    fn http_client(&self) -> Result<&HttpClient> {
+        if let Some(client) = self.http_client.get() {
+            return Ok(client);
+        }
+        let client = self.build_http_client()?;
+        Ok(self.http_client.get_or_init(|| client))
+    }
+
+    fn build_http_client(&self) -> reqwest::Result<HttpClient> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, self.accept.clone());
+        let mut builder = HttpClient::builder().default_headers(headers);
+
+        if self.endpoint.starts_with("https://") {
+            builder = builder
+                .use_rustls_tls()
+                .min_tls_version(tls::Version::TLS_1_2)
+                .max_tls_version(tls::Version::TLS_1_3);
+
+            if self.skip_tls_peer_verification {
+                builder = builder.danger_accept_invalid_certs(true);
+            };
+
+            if let Some(cert) = &self.ca_certificate {
+                builder = builder.add_root_certificate(cert.clone());
+            }
+        }
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        builder = match self.http_version {
+            HttpVersion::Negotiated => builder,
+            HttpVersion::Http1Only => builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+
+        builder.build()
+    }
+
+    fn ok_or_status_code_error_except_503(
+        &self,
+        response: HttpClientResponse,
+    ) -> Result<HttpClientResponse> {
+        let status = response.status();
+        if status.is_client_error() {
+            return Err(Self::client_error(response));
+        }
+
+        // 503 Service Unavailable is used to indicate a health check failure.
+        // In this case, we want to parse the response and provide a more specific error.
+        if status.is_server_error() && status.as_u16() != 503 {
+            return Err(Error::ServerErrorResponse(status.as_u16(), response));
+        }
+
+        Ok(response)
+    }
+
+    fn client_error(response: HttpClientResponse) -> Error {
+        match response.status().as_u16() {
+            401 => Error::AuthenticationFailed(response),
+            403 => Error::AccessRefused(response),
+            code => Error::ClientErrorResponse(code, response),
+        }
+    }
+
+    fn rooted_path(&self, path: &str) -> String {
+        utils::rooted_path(self.endpoint, self.path_prefix.as_deref(), path)
+    }
+}
+
+impl<'a> Default for Client<'a> {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:15672",
+            username: "guest",
+            password: "guest",
+            ca_certificate: None,
+            skip_tls_peer_verification: false,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            timeout: None,
+            connect_timeout: None,
+            http_version: HttpVersion::Negotiated,
+            accept: HeaderValue::from_static("application/json"),
+            path_prefix: None,
+            preemptive_auth: true,
+            lenient_deserialization: false,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            request_inspector: None,
+            http_client: Arc::new(OnceLock::new()),
+        }
+    }
+}
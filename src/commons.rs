@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
 
 /// Exchange types. Most variants are for exchange types included with modern RabbitMQ distributions.
 /// For custom types provided by 3rd party plugins, use the `Plugin(String)` variant.
-#[derive(Serialize, Debug, PartialEq, Eq)]
-#[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
+/// (De)serializes to and from the exact type name, so plugin-provided types round-trip unchanged.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
 pub enum ExchangeType {
     /// Fanout exchange
     Fanout,
@@ -77,12 +80,34 @@ impl From<ExchangeType> for String {
     }
 }
 
-#[derive(Debug, Serialize, Clone, Copy)]
-#[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
+/// Returns `true` if a topic exchange binding `pattern` matches `routing_key`.
+/// In patterns, `*` matches exactly one dot-separated word and `#` matches zero or more words.
+pub fn topic_pattern_matches(pattern: &str, routing_key: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('.').collect();
+    let words: Vec<&str> = routing_key.split('.').collect();
+    topic_words_match(&pattern, &words)
+}
+
+fn topic_words_match(pattern: &[&str], words: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => words.is_empty(),
+        Some((&"#", rest)) => (0..=words.len()).any(|i| topic_words_match(rest, &words[i..])),
+        Some((p, rest)) => match words.split_first() {
+            Some((w, remaining)) => (*p == "*" || p == w) && topic_words_match(rest, remaining),
+            None => false,
+        },
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
 pub enum QueueType {
     Classic,
     Quorum,
     Stream,
+    /// A queue type provided by a plugin, e.g. MQTT QoS 0 queues,
+    /// or introduced by a later RabbitMQ version
+    Other(String),
 }
 
 impl From<&str> for QueueType {
@@ -91,19 +116,14 @@ impl From<&str> for QueueType {
             "classic" => QueueType::Classic,
             "quorum" => QueueType::Quorum,
             "stream" => QueueType::Stream,
-            _ => QueueType::Classic,
+            other => QueueType::Other(other.to_owned()),
         }
     }
 }
 
 impl From<String> for QueueType {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "classic" => QueueType::Classic,
-            "quorum" => QueueType::Quorum,
-            "stream" => QueueType::Stream,
-            _ => QueueType::Classic,
-        }
+        QueueType::from(value.as_str())
     }
 }
 
@@ -113,6 +133,7 @@ impl From<QueueType> for String {
             QueueType::Classic => "classic".to_owned(),
             QueueType::Quorum => "quorum".to_owned(),
             QueueType::Stream => "stream".to_owned(),
+            QueueType::Other(queue_type) => queue_type,
         }
     }
 }
@@ -133,6 +154,15 @@ impl BindingDestinationType {
     }
 }
 
+impl fmt::Display for BindingDestinationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BindingDestinationType::Queue => write!(f, "queue"),
+            BindingDestinationType::Exchange => write!(f, "exchange"),
+        }
+    }
+}
+
 impl From<&str> for BindingDestinationType {
     fn from(value: &str) -> Self {
         match value {
@@ -303,3 +333,353 @@ impl From<UserLimitTarget> for String {
         }
     }
 }
+
+/// User tags. Tags are used by the management UI and HTTP API
+/// to decide what a user can access.
+/// For tags that have no special meaning to RabbitMQ, use the `Custom(String)` variant.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum UserTag {
+    Administrator,
+    Monitoring,
+    Management,
+    PolicyMaker,
+    Impersonator,
+    Custom(String),
+}
+
+impl From<&str> for UserTag {
+    fn from(value: &str) -> Self {
+        match value {
+            "administrator" => UserTag::Administrator,
+            "monitoring" => UserTag::Monitoring,
+            "management" => UserTag::Management,
+            "policymaker" => UserTag::PolicyMaker,
+            "impersonator" => UserTag::Impersonator,
+            other => UserTag::Custom(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserTag {
+    fn from(value: String) -> Self {
+        UserTag::from(value.as_str())
+    }
+}
+
+impl From<UserTag> for String {
+    fn from(value: UserTag) -> Self {
+        match value {
+            UserTag::Administrator => "administrator".to_owned(),
+            UserTag::Monitoring => "monitoring".to_owned(),
+            UserTag::Management => "management".to_owned(),
+            UserTag::PolicyMaker => "policymaker".to_owned(),
+            UserTag::Impersonator => "impersonator".to_owned(),
+            UserTag::Custom(tag) => tag,
+        }
+    }
+}
+
+/// Acknowledgement mode used by shovels and federation links.
+/// `OnConfirm` is the safest option: messages are acknowledged at the source
+/// only after the destination has confirmed them.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AckMode {
+    #[default]
+    OnConfirm,
+    OnPublish,
+    NoAck,
+}
+
+impl From<&str> for AckMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "on-confirm" => AckMode::OnConfirm,
+            "on-publish" => AckMode::OnPublish,
+            "no-ack" => AckMode::NoAck,
+            _ => AckMode::OnConfirm,
+        }
+    }
+}
+
+impl From<String> for AckMode {
+    fn from(value: String) -> Self {
+        AckMode::from(value.as_str())
+    }
+}
+
+impl From<AckMode> for String {
+    fn from(value: AckMode) -> Self {
+        match value {
+            AckMode::OnConfirm => "on-confirm".to_owned(),
+            AckMode::OnPublish => "on-publish".to_owned(),
+            AckMode::NoAck => "no-ack".to_owned(),
+        }
+    }
+}
+
+/// The unit of the window used by the certificate expiration health check.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeUnit::Days => write!(f, "days"),
+            TimeUnit::Weeks => write!(f, "weeks"),
+            TimeUnit::Months => write!(f, "months"),
+            TimeUnit::Years => write!(f, "years"),
+        }
+    }
+}
+
+/// What a queue does when it reaches its maximum length (the `x-overflow` argument).
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Overflow {
+    /// Drops or dead-letters the oldest messages
+    #[default]
+    DropHead,
+    /// Rejects new publishes
+    RejectPublish,
+    /// Rejects new publishes and dead-letters them
+    RejectPublishDlx,
+}
+
+/// The resource a node-local alarm is in effect for.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum AlarmResource {
+    Memory,
+    Disk,
+    Other(String),
+}
+
+impl From<&str> for AlarmResource {
+    fn from(value: &str) -> Self {
+        match value {
+            "memory" => AlarmResource::Memory,
+            "disk" => AlarmResource::Disk,
+            other => AlarmResource::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlarmResource {
+    fn from(value: String) -> Self {
+        AlarmResource::from(value.as_str())
+    }
+}
+
+impl From<AlarmResource> for String {
+    fn from(value: AlarmResource) -> Self {
+        match value {
+            AlarmResource::Memory => "memory".to_owned(),
+            AlarmResource::Disk => "disk".to_owned(),
+            AlarmResource::Other(resource) => resource,
+        }
+    }
+}
+
+/// The state of a feature flag on the cluster.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagState {
+    Enabled,
+    Disabled,
+    /// The flag is being enabled
+    StateChanging,
+    /// Some nodes do not support the flag, so it cannot be enabled
+    Unavailable,
+}
+
+/// How mature a feature flag is. Required flags must be enabled before an upgrade.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum FeatureFlagStability {
+    Required,
+    Stable,
+    /// Cannot be disabled once enabled and may not be supported by future versions,
+    /// so should not be enabled in production
+    Experimental,
+    /// Flags that back deprecated features
+    Deprecated,
+    /// A stability level introduced by a later RabbitMQ version
+    Other(String),
+}
+
+impl From<&str> for FeatureFlagStability {
+    fn from(value: &str) -> Self {
+        match value {
+            "required" => FeatureFlagStability::Required,
+            "stable" => FeatureFlagStability::Stable,
+            "experimental" => FeatureFlagStability::Experimental,
+            "deprecated" => FeatureFlagStability::Deprecated,
+            other => FeatureFlagStability::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeatureFlagStability {
+    fn from(value: String) -> Self {
+        FeatureFlagStability::from(value.as_str())
+    }
+}
+
+impl From<FeatureFlagStability> for String {
+    fn from(value: FeatureFlagStability) -> Self {
+        match value {
+            FeatureFlagStability::Required => "required".to_owned(),
+            FeatureFlagStability::Stable => "stable".to_owned(),
+            FeatureFlagStability::Experimental => "experimental".to_owned(),
+            FeatureFlagStability::Deprecated => "deprecated".to_owned(),
+            FeatureFlagStability::Other(stability) => stability,
+        }
+    }
+}
+
+/// How far along its deprecation a feature is. Each phase is more restrictive than the previous one.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeprecationPhase {
+    /// The feature can be used unless it is explicitly denied in the configuration
+    PermittedByDefault,
+    /// The feature cannot be used unless it is explicitly permitted in the configuration
+    DeniedByDefault,
+    /// The feature cannot be used anymore
+    Disconnected,
+    /// The feature was removed
+    Removed,
+}
+
+/// A size in bytes, for limits such as `x-max-length-bytes`.
+///
+/// Parses human-friendly values such as `"500MB"`, `"2GB"` or `"1TB"`.
+/// Units are binary: `1KB` is 1024 bytes and `1GB` is 1024 * 1024 * 1024 bytes.
+/// The `KiB`, `MiB`, `GiB` and `TiB` spellings are accepted as well. A plain number is a byte count.
+///
+/// Serializes to a plain byte count, which is what RabbitMQ expects.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "ByteCapacityRepr", into = "u64")]
+pub struct ByteCapacity(u64);
+
+const KIBIBYTE: u64 = 1024;
+const BYTE_UNITS: [(&str, u64); 4] = [
+    ("TB", KIBIBYTE * KIBIBYTE * KIBIBYTE * KIBIBYTE),
+    ("GB", KIBIBYTE * KIBIBYTE * KIBIBYTE),
+    ("MB", KIBIBYTE * KIBIBYTE),
+    ("KB", KIBIBYTE),
+];
+
+impl ByteCapacity {
+    pub fn from_bytes(bytes: u64) -> Self {
+        ByteCapacity(bytes)
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ByteCapacityParseError {
+    #[error("byte capacity value is empty")]
+    Empty,
+    #[error("invalid byte capacity number: {0}")]
+    InvalidNumber(String),
+    #[error("unknown byte capacity unit: {0}")]
+    UnknownUnit(String),
+    #[error("byte capacity value is too large")]
+    Overflow,
+}
+
+impl FromStr for ByteCapacity {
+    type Err = ByteCapacityParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ByteCapacityParseError::Empty);
+        }
+
+        let split_at = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split_at);
+        let n = number
+            .parse::<u64>()
+            .map_err(|_| ByteCapacityParseError::InvalidNumber(number.to_owned()))?;
+
+        let multiplier = match unit.trim().to_uppercase().as_str() {
+            "" | "B" => 1,
+            "KB" | "KIB" | "K" => KIBIBYTE,
+            "MB" | "MIB" | "M" => KIBIBYTE * KIBIBYTE,
+            "GB" | "GIB" | "G" => KIBIBYTE * KIBIBYTE * KIBIBYTE,
+            "TB" | "TIB" | "T" => KIBIBYTE * KIBIBYTE * KIBIBYTE * KIBIBYTE,
+            _ => return Err(ByteCapacityParseError::UnknownUnit(unit.trim().to_owned())),
+        };
+
+        n.checked_mul(multiplier)
+            .map(ByteCapacity)
+            .ok_or(ByteCapacityParseError::Overflow)
+    }
+}
+
+/// Formats the value using the largest unit that represents it exactly, e.g. `2GB`.
+impl fmt::Display for ByteCapacity {
+    // u64::is_multiple_of requires Rust 1.87
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (unit, size) in BYTE_UNITS {
+            if self.0 != 0 && self.0 % size == 0 {
+                return write!(f, "{}{}", self.0 / size, unit);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl From<u64> for ByteCapacity {
+    fn from(value: u64) -> Self {
+        ByteCapacity(value)
+    }
+}
+
+impl From<ByteCapacity> for u64 {
+    fn from(value: ByteCapacity) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<&str> for ByteCapacity {
+    type Error = ByteCapacityParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ByteCapacityRepr {
+    Bytes(u64),
+    Human(String),
+}
+
+impl TryFrom<ByteCapacityRepr> for ByteCapacity {
+    type Error = ByteCapacityParseError;
+
+    fn try_from(value: ByteCapacityRepr) -> Result<Self, Self::Error> {
+        match value {
+            ByteCapacityRepr::Bytes(n) => Ok(ByteCapacity(n)),
+            ByteCapacityRepr::Human(s) => s.parse(),
+        }
+    }
+}
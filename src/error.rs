@@ -0,0 +1,147 @@
+use crate::{requests, responses};
+use reqwest::{header::InvalidHeaderValue, Method, Url};
+use std::fmt;
+use thiserror::Error;
+
+/// Errors returned by both the blocking and the async client.
+///
+/// The variants that carry an HTTP response are generic over the response type,
+/// see [`crate::blocking::Error`] and [`crate::api::Error`].
+#[derive(Error, Debug)]
+pub enum Error<R: HttpResponse> {
+    #[error("encountered an error when performing an HTTP request")]
+    RequestError(#[from] reqwest::Error),
+    #[error(
+        "API responded with a client error: status code of {0}{}",
+        RequestSuffix(.1)
+    )]
+    ClientErrorResponse(u16, R),
+    #[error(
+        "API responded with 401 Unauthorized: check the username and password{}",
+        RequestSuffix(.0)
+    )]
+    AuthenticationFailed(R),
+    #[error(
+        "API responded with 403 Forbidden: the user lacks the required tags or permissions{}",
+        RequestSuffix(.0)
+    )]
+    AccessRefused(R),
+    #[error(
+        "API responded with a server error: status code of {0}{}",
+        RequestSuffix(.1)
+    )]
+    ServerErrorResponse(u16, R),
+    #[error("Health check failed: {}", .0.reason())]
+    HealthCheckFailed(responses::HealthCheckFailureDetails),
+    #[error("Could not find the requested resource")]
+    NotFound(),
+    #[error("Can't delete a binding: multiple matching bindings found")]
+    ManyMatchingBindings(),
+    #[error("alternate exchanges form a cycle: {0:?}")]
+    AlternateExchangeCycle(Vec<String>),
+    #[error("failed to deserialize the response body")]
+    DeserializationError(serde_json::Error),
+    #[error("failed to copy the response body to the provided writer")]
+    WriteError(std::io::Error),
+    #[error("could not convert provided value into an HTTP header value")]
+    InvalidHeaderValue(#[from] InvalidHeaderValue),
+    #[error("queue already exists with different properties: {differing_fields:?}")]
+    Inequivalent {
+        existing: Box<responses::QueueInfo>,
+        requested: requests::QueueParamsSummary,
+        differing_fields: Vec<String>,
+    },
+    #[error("could not enable feature flag {name}: {reason}")]
+    FeatureFlagNotEnabled { name: String, reason: String },
+    #[error(
+        "feature flag {name} is experimental, use enable_experimental_feature_flag to enable it"
+    )]
+    ExperimentalFeatureFlag { name: String },
+    #[error("requires RabbitMQ {required} or later but the node runs {actual}")]
+    UnsupportedServerVersion { required: String, actual: String },
+    #[error("the user_id property {user_id} does not match the authenticated user {username}")]
+    UserIdMismatch { user_id: String, username: String },
+    #[error("an unspecified error")]
+    Other,
+}
+
+impl<R: HttpResponse> Error<R> {
+    /// Returns the method and path of the request the API responded to with an error.
+    ///
+    /// Returns `None` for errors that do not carry a response. Transport errors
+    /// ([`Error::RequestError`]) include the URL in their message instead.
+    pub fn request_context(&self) -> Option<&RequestContext> {
+        match self {
+            Error::ClientErrorResponse(_, response)
+            | Error::ServerErrorResponse(_, response)
+            | Error::AuthenticationFailed(response)
+            | Error::AccessRefused(response) => response.request_context(),
+            _ => None,
+        }
+    }
+}
+
+/// The method and path of a request, for example, `GET /api/queues/%2F/orders`.
+/// Never includes credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    pub method: Method,
+    /// The path of the request URL, including the query string, if any
+    pub path: String,
+}
+
+impl RequestContext {
+    pub(crate) fn new(method: &Method, url: &Url) -> Self {
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_owned(),
+        };
+        Self {
+            method: method.clone(),
+            path,
+        }
+    }
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+/// Appends the request a response belongs to, if known, to an error message.
+struct RequestSuffix<'a, R>(&'a R);
+
+impl<R: HttpResponse> fmt::Display for RequestSuffix<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.request_context() {
+            Some(context) => write!(f, " ({})", context),
+            None => Ok(()),
+        }
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for reqwest::blocking::Response {}
+    impl Sealed for reqwest::Response {}
+}
+
+/// Implemented by the response types of the blocking and the async client.
+pub trait HttpResponse: private::Sealed {
+    /// Returns the request this is a response to.
+    fn request_context(&self) -> Option<&RequestContext>;
+}
+
+impl HttpResponse for reqwest::blocking::Response {
+    fn request_context(&self) -> Option<&RequestContext> {
+        self.extensions().get::<RequestContext>()
+    }
+}
+
+impl HttpResponse for reqwest::Response {
+    fn request_context(&self) -> Option<&RequestContext> {
+        self.extensions().get::<RequestContext>()
+    }
+}
@@ -1,5 +1,8 @@
+pub mod api;
 pub mod blocking;
 pub mod commons;
+pub mod error;
 pub mod password_hashing;
 pub mod requests;
 pub mod responses;
+mod utils;
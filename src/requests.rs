@@ -1,6 +1,10 @@
-use crate::commons::{ExchangeType, PolicyTarget, QueueType};
+use crate::{
+    commons::{AckMode, ByteCapacity, ExchangeType, Overflow, PolicyTarget, QueueType},
+    responses,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
+use std::time::Duration;
 
 #[derive(Serialize)]
 pub struct VirtualHostParams<'a> {
@@ -117,6 +121,14 @@ impl<'a> QueueParams<'a> {
         }
     }
 
+    /// Limits the queue or stream to `capacity` bytes (the `x-max-length-bytes` argument).
+    pub fn with_max_length_bytes(mut self, capacity: ByteCapacity) -> Self {
+        self.arguments
+            .get_or_insert_with(Map::new)
+            .insert("x-max-length-bytes".to_owned(), json!(capacity.bytes()));
+        self
+    }
+
     pub fn combined_args(optional_args: XArguments, queue_type: &QueueType) -> XArguments {
         let mut result = Map::<String, Value>::new();
         result.insert("x-queue-type".to_owned(), json!(queue_type));
@@ -129,6 +141,121 @@ impl<'a> QueueParams<'a> {
     }
 }
 
+/// Turns a queue listed on one cluster (or virtual host) into parameters
+/// that declare an equivalent queue elsewhere.
+///
+/// Only the properties set at declaration time carry over: the type, durability,
+/// auto-deletion, exclusivity and optional arguments. Policies must be declared separately,
+/// and runtime state such as messages, consumers or replica placement is not part of a declaration.
+impl<'a> From<&'a responses::QueueInfo> for QueueParams<'a> {
+    fn from(info: &'a responses::QueueInfo) -> Self {
+        Self {
+            name: &info.name,
+            queue_type: info.queue_type.clone(),
+            durable: info.durable,
+            auto_delete: info.auto_delete,
+            exclusive: info.exclusive,
+            arguments: Self::combined_args(Some(info.arguments.clone()), &info.queue_type),
+        }
+    }
+}
+
+/// Builds the optional arguments of a queue, for example:
+///
+/// ```
+/// use rabbitmq_http_client::{commons::Overflow, requests::{QueueArgumentsBuilder, QueueParams}};
+/// use std::time::Duration;
+///
+/// let args = QueueArgumentsBuilder::new()
+///     .max_length(10_000)
+///     .overflow(Overflow::RejectPublish)
+///     .message_ttl(Duration::from_secs(60))
+///     .build();
+/// let params = QueueParams::new_quorum_queue("orders", args);
+/// ```
+///
+/// Arguments without a dedicated setter can be set with [`QueueArgumentsBuilder::argument`].
+#[derive(Debug, Clone, Default)]
+pub struct QueueArgumentsBuilder {
+    arguments: Map<String, Value>,
+}
+
+impl QueueArgumentsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `x-max-length`, the maximum number of messages in the queue.
+    pub fn max_length(self, max_length: u64) -> Self {
+        self.argument("x-max-length", json!(max_length))
+    }
+
+    /// Sets `x-max-length-bytes`, the maximum total size of message bodies in the queue.
+    pub fn max_length_bytes(self, max_length_bytes: u64) -> Self {
+        self.argument("x-max-length-bytes", json!(max_length_bytes))
+    }
+
+    /// Sets `x-message-ttl`. RabbitMQ uses millisecond precision.
+    pub fn message_ttl(self, ttl: Duration) -> Self {
+        self.argument("x-message-ttl", json!(ttl.as_millis() as u64))
+    }
+
+    /// Sets `x-expires`, for how long the queue can be unused before it is deleted.
+    pub fn expires(self, after: Duration) -> Self {
+        self.argument("x-expires", json!(after.as_millis() as u64))
+    }
+
+    /// Sets `x-dead-letter-exchange`.
+    pub fn dead_letter_exchange(self, exchange: &str) -> Self {
+        self.argument("x-dead-letter-exchange", json!(exchange))
+    }
+
+    /// Sets `x-dead-letter-routing-key`.
+    pub fn dead_letter_routing_key(self, routing_key: &str) -> Self {
+        self.argument("x-dead-letter-routing-key", json!(routing_key))
+    }
+
+    /// Sets `x-overflow`.
+    pub fn overflow(self, overflow: Overflow) -> Self {
+        self.argument("x-overflow", json!(overflow))
+    }
+
+    /// Sets an arbitrary argument, replacing any previous value.
+    pub fn argument(mut self, key: &str, value: Value) -> Self {
+        self.arguments.insert(key.to_owned(), value);
+        self
+    }
+
+    /// Returns the arguments in the form accepted by [`QueueParams`].
+    pub fn build(self) -> XArguments {
+        Some(self.arguments)
+    }
+}
+
+/// An owned copy of the [`QueueParams`] of a declaration, kept for error reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueParamsSummary {
+    pub name: String,
+    pub queue_type: QueueType,
+    pub durable: bool,
+    pub auto_delete: bool,
+    pub exclusive: bool,
+    pub arguments: XArguments,
+}
+
+impl From<&QueueParams<'_>> for QueueParamsSummary {
+    fn from(params: &QueueParams<'_>) -> Self {
+        Self {
+            name: params.name.to_owned(),
+            queue_type: params.queue_type.clone(),
+            durable: params.durable,
+            auto_delete: params.auto_delete,
+            exclusive: params.exclusive,
+            arguments: params.arguments.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ExchangeParams<'a> {
     pub name: &'a str,
@@ -238,6 +365,40 @@ impl<'a> ExchangeParams<'a> {
     }
 }
 
+/// Turns an exchange listed on one cluster (or virtual host) into parameters
+/// that declare an equivalent exchange elsewhere.
+impl<'a> From<&'a responses::ExchangeInfo> for ExchangeParams<'a> {
+    fn from(info: &'a responses::ExchangeInfo) -> Self {
+        Self {
+            name: &info.name,
+            exchange_type: info.exchange_type.clone(),
+            durable: info.durable,
+            auto_delete: info.auto_delete,
+            arguments: Some(info.arguments.clone()),
+        }
+    }
+}
+
+/// A binding of a queue to an exchange, see `Client::bind_queues`.
+#[derive(Debug, Clone)]
+pub struct BindingSpec<'a> {
+    pub queue: &'a str,
+    pub exchange: &'a str,
+    pub routing_key: Option<&'a str>,
+    pub arguments: XArguments,
+}
+
+impl<'a> BindingSpec<'a> {
+    pub fn new(queue: &'a str, exchange: &'a str, routing_key: Option<&'a str>) -> Self {
+        Self {
+            queue,
+            exchange,
+            routing_key,
+            arguments: None,
+        }
+    }
+}
+
 pub type RuntimeParameterValue = Map<String, Value>;
 
 #[derive(Serialize, Deserialize)]
@@ -248,6 +409,109 @@ pub struct RuntimeParameterDefinition {
     pub value: RuntimeParameterValue,
 }
 
+/// What a shovel consumes from or publishes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShovelEndpoint<'a> {
+    Queue(&'a str),
+    /// An exchange, optionally with the routing key to bind (for a source) or publish with (for a destination)
+    Exchange {
+        name: &'a str,
+        routing_key: Option<&'a str>,
+    },
+}
+
+/// When a shovel deletes itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShovelDeleteAfter {
+    #[default]
+    Never,
+    /// After transferring the messages that were in the source queue when the shovel started
+    QueueLength,
+    /// After transferring this many messages
+    Messages(u64),
+}
+
+/// A dynamic shovel that uses AMQP 0-9-1 on both ends.
+#[derive(Debug, Clone)]
+pub struct ShovelParams<'a> {
+    pub vhost: &'a str,
+    pub name: &'a str,
+    pub source_uri: &'a str,
+    pub source: ShovelEndpoint<'a>,
+    pub destination_uri: &'a str,
+    pub destination: ShovelEndpoint<'a>,
+    pub prefetch_count: Option<u32>,
+    pub ack_mode: AckMode,
+    pub delete_after: ShovelDeleteAfter,
+}
+
+impl<'a> ShovelParams<'a> {
+    /// A shovel that moves messages between two queues and acknowledges them on confirm.
+    pub fn queue_to_queue(
+        vhost: &'a str,
+        name: &'a str,
+        source_uri: &'a str,
+        source_queue: &'a str,
+        destination_uri: &'a str,
+        destination_queue: &'a str,
+    ) -> Self {
+        Self {
+            vhost,
+            name,
+            source_uri,
+            source: ShovelEndpoint::Queue(source_queue),
+            destination_uri,
+            destination: ShovelEndpoint::Queue(destination_queue),
+            prefetch_count: None,
+            ack_mode: AckMode::default(),
+            delete_after: ShovelDeleteAfter::default(),
+        }
+    }
+
+    fn insert_endpoint(value: &mut RuntimeParameterValue, prefix: &str, endpoint: &ShovelEndpoint) {
+        match endpoint {
+            ShovelEndpoint::Queue(name) => {
+                value.insert(format!("{}-queue", prefix), json!(name));
+            }
+            ShovelEndpoint::Exchange { name, routing_key } => {
+                value.insert(format!("{}-exchange", prefix), json!(name));
+                if let Some(rk) = routing_key {
+                    value.insert(format!("{}-exchange-key", prefix), json!(rk));
+                }
+            }
+        }
+    }
+}
+
+impl From<&ShovelParams<'_>> for RuntimeParameterDefinition {
+    fn from(params: &ShovelParams<'_>) -> Self {
+        let mut value = RuntimeParameterValue::new();
+        value.insert("src-protocol".to_owned(), json!("amqp091"));
+        value.insert("src-uri".to_owned(), json!(params.source_uri));
+        ShovelParams::insert_endpoint(&mut value, "src", &params.source);
+        value.insert("dest-protocol".to_owned(), json!("amqp091"));
+        value.insert("dest-uri".to_owned(), json!(params.destination_uri));
+        ShovelParams::insert_endpoint(&mut value, "dest", &params.destination);
+        value.insert("ack-mode".to_owned(), json!(params.ack_mode));
+        if let Some(prefetch_count) = params.prefetch_count {
+            value.insert("src-prefetch-count".to_owned(), json!(prefetch_count));
+        }
+        let delete_after = match params.delete_after {
+            ShovelDeleteAfter::Never => json!("never"),
+            ShovelDeleteAfter::QueueLength => json!("queue-length"),
+            ShovelDeleteAfter::Messages(n) => json!(n),
+        };
+        value.insert("src-delete-after".to_owned(), delete_after);
+
+        Self {
+            name: params.name.to_owned(),
+            vhost: params.vhost.to_owned(),
+            component: "shovel".to_owned(),
+            value,
+        }
+    }
+}
+
 pub type PolicyDefinition = Option<Map<String, Value>>;
 
 #[derive(Serialize)]
@@ -261,6 +525,100 @@ pub struct PolicyParams<'a> {
     pub definition: PolicyDefinition,
 }
 
+impl<'a> PolicyParams<'a> {
+    /// Builds a policy that matches exactly one object (queue, stream or exchange)
+    /// by anchoring the pattern and escaping regular expression metacharacters
+    /// in `object_name`.
+    ///
+    /// The generated pattern is owned by the returned value,
+    /// use [`ExactNamePolicyParams::params`] to declare the policy.
+    pub fn for_exact_name(
+        vhost: &'a str,
+        name: &'a str,
+        object_name: &str,
+        apply_to: PolicyTarget,
+        priority: i32,
+        definition: PolicyDefinition,
+    ) -> ExactNamePolicyParams<'a> {
+        ExactNamePolicyParams {
+            vhost,
+            name,
+            pattern: format!("^{}$", escape_regex(object_name)),
+            apply_to,
+            priority,
+            definition,
+        }
+    }
+}
+
+/// A policy that matches exactly one object, see [`PolicyParams::for_exact_name`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactNamePolicyParams<'a> {
+    pub vhost: &'a str,
+    pub name: &'a str,
+    pattern: String,
+    pub apply_to: PolicyTarget,
+    pub priority: i32,
+    pub definition: PolicyDefinition,
+}
+
+impl ExactNamePolicyParams<'_> {
+    /// The anchored, escaped pattern, for example, `^orders\.v1$`.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the parameters accepted by `Client::declare_policy`.
+    pub fn params(&self) -> PolicyParams<'_> {
+        PolicyParams {
+            vhost: self.vhost,
+            name: self.name,
+            pattern: &self.pattern,
+            apply_to: self.apply_to.clone(),
+            priority: self.priority,
+            definition: self.definition.clone(),
+        }
+    }
+}
+
+/// Turns a policy listed on one cluster into parameters that declare it elsewhere.
+impl<'a> From<&'a responses::Policy> for PolicyParams<'a> {
+    fn from(policy: &'a responses::Policy) -> Self {
+        Self {
+            vhost: &policy.vhost,
+            name: &policy.name,
+            pattern: &policy.pattern,
+            apply_to: policy.apply_to.clone(),
+            priority: i32::from(policy.priority),
+            definition: policy.definition.clone(),
+        }
+    }
+}
+
+fn escape_regex(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(feature = "regex")]
+impl<'a> PolicyParams<'a> {
+    /// Checks that the pattern compiles as a regular expression.
+    ///
+    /// RabbitMQ compiles policy patterns as Erlang (PCRE) regular expressions,
+    /// so this cannot catch every difference, but it does catch typos
+    /// such as unbalanced groups or brackets that would otherwise
+    /// produce a policy that silently matches nothing.
+    pub fn validate(&self) -> Result<(), regex::Error> {
+        regex::Regex::new(self.pattern).map(|_| ())
+    }
+}
+
 #[derive(Serialize)]
 pub struct Permissions<'a> {
     pub user: &'a str,
@@ -269,3 +627,37 @@ pub struct Permissions<'a> {
     pub read: &'a str,
     pub write: &'a str,
 }
+
+/// Properties of a message published with `Client::publish_message`.
+/// Properties that are not set are omitted.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MessageProperties<'a> {
+    /// Identifies the publishing application. Messages published via the HTTP API
+    /// are not attributed to a client connection, so this is the way to tell their producers apart.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<&'a str>,
+    /// Must match the name of the user the client authenticates as,
+    /// otherwise RabbitMQ rejects the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<&'a str>,
+    /// 1 for transient, 2 for persistent messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_mode: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Map<String, Value>>,
+}
+
+impl<'a> MessageProperties<'a> {
+    pub fn with_app_id(app_id: &'a str) -> Self {
+        MessageProperties {
+            app_id: Some(app_id),
+            ..Default::default()
+        }
+    }
+}
@@ -1,18 +1,33 @@
-use crate::commons::{BindingDestinationType, PolicyTarget};
-use serde::Deserialize;
+use crate::commons::{
+    AlarmResource, BindingDestinationType, DeprecationPhase, ExchangeType, FeatureFlagStability,
+    FeatureFlagState, PolicyTarget, QueueType,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_aux::prelude::*;
-use serde_json::Map;
+use serde_json::{json, Map};
+use serde_path_to_error::Segment;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    hash::Hash,
+};
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
 #[allow(dead_code)]
 pub struct VirtualHostMetadata {
     /// Optional tags
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
     /// Optional description
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Default queue type used in this virtual host when clients
     /// do not explicitly specify one
-    pub default_queue_type: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_queue_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_queue_type: Option<QueueType>,
 }
 
 /// Represents a [RabbitMQ virtual host](https://rabbitmq.com/vhosts.html).
@@ -27,9 +42,73 @@ pub struct VirtualHost {
     pub description: Option<String>,
     /// Default queue type used in this virtual host when clients
     /// do not explicitly specify one
-    pub default_queue_type: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_queue_type")]
+    pub default_queue_type: Option<QueueType>,
     /// All virtual host metadata combined
     pub metadata: VirtualHostMetadata,
+    /// Whether message tracing is enabled
+    #[serde(default)]
+    pub tracing: bool,
+
+    /// Total number of messages in all queues in this virtual host
+    #[serde(default)]
+    pub messages: Option<u64>,
+    /// Total number of messages ready for delivery in this virtual host
+    #[serde(default)]
+    pub messages_ready: Option<u64>,
+    /// Total number of delivered but not yet acknowledged messages in this virtual host
+    #[serde(default)]
+    pub messages_unacknowledged: Option<u64>,
+    /// Aggregated message rates, only reported when message stats collection is enabled
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
+}
+
+/// A rate computed by the management plugin over its sample retention window.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[allow(dead_code)]
+pub struct Rate {
+    pub rate: f64,
+}
+
+/// Message counters and rates. Only reported when
+/// the management plugin collects message stats.
+///
+/// Which counters are present depends on the kind of object
+/// (e.g. only exchanges report `publish_in` and `publish_out`),
+/// so all of them are optional.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+#[allow(dead_code)]
+pub struct MessageStats {
+    pub publish: Option<u64>,
+    pub publish_details: Option<Rate>,
+    pub publish_in: Option<u64>,
+    pub publish_in_details: Option<Rate>,
+    pub publish_out: Option<u64>,
+    pub publish_out_details: Option<Rate>,
+    pub confirm: Option<u64>,
+    pub confirm_details: Option<Rate>,
+    pub return_unroutable: Option<u64>,
+    pub return_unroutable_details: Option<Rate>,
+    pub drop_unroutable: Option<u64>,
+    pub drop_unroutable_details: Option<Rate>,
+    pub deliver: Option<u64>,
+    pub deliver_details: Option<Rate>,
+    pub deliver_no_ack: Option<u64>,
+    pub deliver_no_ack_details: Option<Rate>,
+    pub get: Option<u64>,
+    pub get_details: Option<Rate>,
+    pub get_no_ack: Option<u64>,
+    pub get_no_ack_details: Option<Rate>,
+    pub get_empty: Option<u64>,
+    pub get_empty_details: Option<Rate>,
+    pub deliver_get: Option<u64>,
+    pub deliver_get_details: Option<Rate>,
+    pub redeliver: Option<u64>,
+    pub redeliver_details: Option<Rate>,
+    pub ack: Option<u64>,
+    pub ack_details: Option<Rate>,
 }
 
 pub type EnforcedLimits = Map<String, serde_json::Value>;
@@ -38,7 +117,10 @@ pub type EnforcedLimits = Map<String, serde_json::Value>;
 #[allow(dead_code)]
 pub struct VirtualHostLimits {
     pub vhost: String,
-    #[serde(rename(deserialize = "value"))]
+    #[serde(
+        rename(deserialize = "value"),
+        deserialize_with = "deserialize_map_or_empty_seq"
+    )]
     pub limits: EnforcedLimits,
 }
 
@@ -47,7 +129,10 @@ pub struct VirtualHostLimits {
 pub struct UserLimits {
     #[serde(rename(deserialize = "user"))]
     pub username: String,
-    #[serde(rename(deserialize = "value"))]
+    #[serde(
+        rename(deserialize = "value"),
+        deserialize_with = "deserialize_map_or_empty_seq"
+    )]
     pub limits: EnforcedLimits,
 }
 
@@ -59,6 +144,14 @@ pub struct User {
     pub password_hash: String,
 }
 
+/// The user the client is authenticated as, see `GET /api/whoami`.
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct CurrentUser {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
 /// Represents a client connection.
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
@@ -94,8 +187,17 @@ pub struct Connection {
     #[serde(rename(deserialize = "channels"))]
     #[serde(default)]
     pub channel_count: u16,
+    /// Negotiated heartbeat interval in seconds. Zero means heartbeats are disabled.
+    #[serde(rename(deserialize = "timeout"))]
+    #[serde(default)]
+    pub heartbeat: Option<u32>,
+    /// Negotiated maximum frame size in bytes.
+    #[serde(default)]
+    pub frame_max: Option<u32>,
     /// Client-provided properties (metadata and capabilities).
     pub client_properties: ClientProperties,
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -112,18 +214,22 @@ pub struct ClientProperties {
     pub capabilities: Option<ClientCapabilities>,
 }
 
+/// Capabilities a client advertises. Many clients only advertise some of them,
+/// the others default to `false`.
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct ClientCapabilities {
+    #[serde(default)]
     pub authentication_failure_close: bool,
-    #[serde(rename(deserialize = "basic.nack"))]
+    #[serde(default, rename(deserialize = "basic.nack"))]
     pub basic_nack: bool,
-    #[serde(rename(deserialize = "connection.blocked"))]
+    #[serde(default, rename(deserialize = "connection.blocked"))]
     pub connection_blocked: bool,
-    #[serde(rename(deserialize = "consumer_cancel_notify"))]
+    #[serde(default, rename(deserialize = "consumer_cancel_notify"))]
     pub consumer_cancel_notify: bool,
-    #[serde(rename(deserialize = "exchange_exchange_bindings"))]
+    #[serde(default, rename(deserialize = "exchange_exchange_bindings"))]
     pub exchange_to_exchange_bindings: bool,
+    #[serde(default)]
     pub publisher_confirms: bool,
 }
 
@@ -150,8 +256,13 @@ pub struct Channel {
     #[serde(rename(deserialize = "confirm"))]
     pub has_publisher_confirms_enabled: bool,
     pub prefetch_count: u32,
+    // not reported when message stats collection is disabled
+    #[serde(default)]
     pub messages_unacknowledged: u32,
+    #[serde(default)]
     pub messages_unconfirmed: u32,
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -196,6 +307,29 @@ pub struct Consumer {
     pub channel_details: ChannelDetails,
 }
 
+impl Consumer {
+    /// The name of the connection the consumer's channel belongs to.
+    pub fn connection_name(&self) -> &str {
+        &self.channel_details.connection_name
+    }
+}
+
+/// A consumer as reported inline by `GET /api/queues/{vhost}/{name}`.
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct ConsumerDetail {
+    pub consumer_tag: String,
+    #[serde(default)]
+    pub active: bool,
+    pub ack_required: bool,
+    pub prefetch_count: u32,
+    #[serde(default)]
+    pub exclusive: bool,
+    #[serde(default)]
+    pub arguments: XArguments,
+    pub channel_details: ChannelDetails,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct NameAndVirtualHost {
@@ -212,12 +346,16 @@ pub struct QueueInfo {
     pub name: String,
     pub vhost: String,
     #[serde(rename(deserialize = "type"))]
-    pub queue_type: String,
+    pub queue_type: QueueType,
     pub durable: bool,
     pub auto_delete: bool,
+    // streams do not report this field
+    #[serde(default)]
     pub exclusive: bool,
+    #[serde(default)]
     pub arguments: XArguments,
 
+    #[serde(default)]
     pub node: String,
     #[serde(default)]
     pub state: String,
@@ -225,15 +363,23 @@ pub struct QueueInfo {
     pub leader: Option<String>,
     pub members: Option<Vec<String>>,
     pub online: Option<Vec<String>>,
+    // only streams will have these
+    pub committed_offset: Option<i64>,
+    pub segments: Option<u64>,
 
     #[serde(default)]
     pub memory: u64,
     #[serde(rename(deserialize = "consumers"))]
     #[serde(default)]
     pub consumer_count: u16,
-    #[serde(default)]
+    // not reported by streams, can be null for classic queues without consumers
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub consumer_utilisation: f32,
     pub exclusive_consumer_tag: Option<String>,
+    /// The connection that declared an exclusive queue. Exclusive queues
+    /// are deleted when this connection closes.
+    #[serde(default)]
+    pub owner_pid_details: Option<ConnectionDetails>,
 
     pub policy: Option<String>,
 
@@ -260,6 +406,68 @@ pub struct QueueInfo {
     #[serde(rename(deserialize = "messages_unacknowledged"))]
     #[serde(default)]
     pub unacknowledged_message_count: u64,
+
+    // only included when a single queue is fetched
+    pub consumer_details: Option<Vec<ConsumerDetail>>,
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
+}
+
+impl QueueInfo {
+    /// The name of the connection that owns an exclusive queue,
+    /// which can be passed to `Client::get_connection_info`.
+    /// Queues that are not exclusive do not have an owner.
+    pub fn owner_connection(&self) -> Option<&str> {
+        self.owner_pid_details.as_ref().map(|d| d.name.as_str())
+    }
+
+    /// Number of replicas (members) of a quorum queue or stream.
+    /// Classic queues do not have members, so this is zero for them.
+    pub fn member_count(&self) -> usize {
+        self.members.as_ref().map_or(0, |v| v.len())
+    }
+
+    /// Number of replicas (members) of a quorum queue or stream that are currently online.
+    pub fn online_member_count(&self) -> usize {
+        self.online.as_ref().map_or(0, |v| v.len())
+    }
+
+    /// The role and availability of every replica (member) of a quorum queue or stream.
+    /// Classic queues do not have members, so this is empty for them.
+    ///
+    /// The HTTP API does not report per-replica offsets or Raft indexes, so replication lag
+    /// cannot be computed from this. An offline replica is the signal that is available.
+    pub fn replica_statuses(&self) -> Vec<QueueReplicaStatus> {
+        let online = self.online.as_deref().unwrap_or_default();
+        self.members
+            .iter()
+            .flatten()
+            .map(|node| QueueReplicaStatus {
+                node: node.clone(),
+                role: if self.leader.as_ref() == Some(node) {
+                    ReplicaRole::Leader
+                } else {
+                    ReplicaRole::Follower
+                },
+                online: online.contains(node),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplicaRole {
+    Leader,
+    Follower,
+}
+
+/// A replica (member) of a quorum queue or stream, see [`QueueInfo::replica_statuses`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueReplicaStatus {
+    /// The node the replica is hosted on
+    pub node: String,
+    pub role: ReplicaRole,
+    pub online: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -268,10 +476,15 @@ pub struct ExchangeInfo {
     pub name: String,
     pub vhost: String,
     #[serde(rename(deserialize = "type"))]
-    pub exchange_type: String,
+    pub exchange_type: ExchangeType,
     pub durable: bool,
     pub auto_delete: bool,
     pub arguments: XArguments,
+    /// Name of the policy applied to this exchange, if any
+    #[serde(default)]
+    pub policy: Option<String>,
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -286,6 +499,35 @@ pub struct BindingInfo {
     pub properties_key: String,
 }
 
+impl BindingInfo {
+    /// Compares binding arguments regardless of key order and numeric representation
+    /// (e.g. `1` and `1.0` are considered equal), which matters for headers exchange bindings.
+    pub fn has_equivalent_arguments(&self, arguments: &XArguments) -> bool {
+        equivalent_arguments(&self.arguments, arguments)
+    }
+}
+
+pub(crate) fn equivalent_arguments(a: &XArguments, b: &XArguments) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .all(|(k, v)| b.get(k).is_some_and(|other| equivalent_values(v, other)))
+}
+
+pub(crate) fn equivalent_values(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => match (x.as_f64(), y.as_f64()) {
+            (Some(fx), Some(fy)) => fx == fy,
+            _ => x == y,
+        },
+        (Value::Array(xs), Value::Array(ys)) => {
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| equivalent_values(x, y))
+        }
+        (Value::Object(x), Value::Object(y)) => equivalent_arguments(x, y),
+        _ => a == b,
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct ClusterNode {
@@ -308,17 +550,188 @@ pub struct ClusterNode {
     #[serde(rename(deserialize = "disk_free_alarm"))]
     pub has_free_disk_space_alarm_in_effect: bool,
     pub rates_mode: String,
+
+    // Erlang runtime and I/O statistics, only reported when
+    // the node's management agent has collected them
+    /// Number of garbage collections
+    #[serde(default)]
+    pub gc_num: Option<u64>,
+    #[serde(default)]
+    pub gc_num_details: Option<Rate>,
+    #[serde(default)]
+    pub gc_bytes_reclaimed: Option<u64>,
+    #[serde(default)]
+    pub gc_bytes_reclaimed_details: Option<Rate>,
+    #[serde(default)]
+    pub io_read_count: Option<u64>,
+    #[serde(default)]
+    pub io_read_count_details: Option<Rate>,
+    #[serde(default)]
+    pub io_write_count: Option<u64>,
+    #[serde(default)]
+    pub io_write_count_details: Option<Rate>,
+    /// Number of Erlang scheduler context switches
+    #[serde(default)]
+    pub context_switches: Option<u64>,
+    #[serde(default)]
+    pub context_switches_details: Option<Rate>,
+}
+
+/// Cluster-wide information and totals, as reported by `GET /api/overview`.
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct Overview {
+    pub cluster_name: String,
+    /// The node that served the request
+    pub node: String,
+    pub rabbitmq_version: String,
+    pub erlang_version: String,
+    pub management_version: String,
+    pub rates_mode: String,
+    pub sample_retention_policies: SampleRetentionPolicies,
+    pub object_totals: ObjectTotals,
+    #[serde(default)]
+    pub queue_totals: QueueTotals,
+    #[serde(default)]
+    pub message_stats: Option<MessageStats>,
 }
 
+/// The number of objects of each kind across the cluster.
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
+pub struct ObjectTotals {
+    pub connections: u64,
+    pub channels: u64,
+    pub exchanges: u64,
+    pub queues: u64,
+    pub consumers: u64,
+}
+
+/// Message counts (and their rates) summed over all queues.
+/// Only reported when the management plugin collects message stats.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+#[allow(dead_code)]
+pub struct QueueTotals {
+    pub messages: Option<u64>,
+    pub messages_details: Option<Rate>,
+    pub messages_ready: Option<u64>,
+    pub messages_ready_details: Option<Rate>,
+    pub messages_unacknowledged: Option<u64>,
+    pub messages_unacknowledged_details: Option<Rate>,
+}
+
+/// How long (in seconds) the management plugin retains samples used to compute rates,
+/// by type of object. Each list contains the age of the oldest sample kept
+/// at each granularity, from the most granular to the least granular one.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct SampleRetentionPolicies {
+    /// Used for cluster-wide (overview) stats
+    pub global: Vec<u64>,
+    /// Used for per-object stats, e.g. of individual queues and connections
+    pub basic: Vec<u64>,
+    /// Used for stats of the relationships between objects, e.g. channel-to-queue
+    pub detailed: Vec<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
 pub struct RuntimeParameter {
     pub name: String,
     pub vhost: String,
     pub component: String,
+    #[serde(deserialize_with = "deserialize_map_or_empty_seq")]
     pub value: RuntimeParameterValue,
 }
 
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct FeatureFlag {
+    pub name: String,
+    pub state: FeatureFlagState,
+    #[serde(default)]
+    pub desc: String,
+    pub stability: FeatureFlagStability,
+    /// The application (usually a plugin) that provides the flag
+    #[serde(default)]
+    pub provided_by: String,
+    #[serde(default)]
+    pub doc_url: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct DeprecatedFeature {
+    pub name: String,
+    #[serde(default)]
+    pub desc: String,
+    pub deprecation_phase: DeprecationPhase,
+    #[serde(default)]
+    pub provided_by: String,
+    #[serde(default)]
+    pub doc_url: String,
+}
+
+/// The body of an error response, such as `{"error": "bad_request", "reason": "..."}`.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct ErrorDetails {
+    pub error: String,
+    pub reason: String,
+}
+
+/// The status of a shovel as reported by `GET /api/shovels`
+/// (requires the `rabbitmq_shovel_management` plugin).
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ShovelStatus {
+    pub name: String,
+    pub node: String,
+    /// Static shovels do not belong to a virtual host
+    #[serde(default)]
+    pub vhost: Option<String>,
+    /// "dynamic" or "static"
+    #[serde(rename(deserialize = "type"))]
+    pub shovel_type: String,
+    /// For example, "starting", "running" or "terminated"
+    pub state: String,
+    #[serde(default)]
+    pub src_uri: Option<String>,
+    #[serde(default)]
+    pub src_queue: Option<String>,
+    #[serde(default)]
+    pub src_exchange: Option<String>,
+    #[serde(default)]
+    pub dest_uri: Option<String>,
+    #[serde(default)]
+    pub dest_queue: Option<String>,
+    #[serde(default)]
+    pub dest_exchange: Option<String>,
+    /// Why the shovel was terminated, if it was
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// The outcome of `Client::shovel_messages_once`.
+///
+/// Messages are moved with at-least-once guarantees: a message is acknowledged
+/// in the source queue only after the destination queue has confirmed it.
+/// If the transfer is interrupted, for example, because it timed out, some messages
+/// may therefore be in both queues, but none are lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShovelTransfer {
+    /// The name of the temporary shovel
+    pub shovel_name: String,
+    /// How many messages were in the source queue when the transfer started.
+    /// Messages published to the source queue later are not moved.
+    pub source_message_count: u64,
+    /// `false` if the transfer timed out or the shovel failed
+    pub completed: bool,
+    /// Why the shovel was terminated, if it failed
+    pub termination_reason: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct ClusterIdentity {
@@ -327,19 +740,19 @@ pub struct ClusterIdentity {
 
 pub type PolicyDefinition = Option<Map<String, serde_json::Value>>;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[allow(dead_code)]
 pub struct Policy {
     pub name: String,
     pub vhost: String,
     pub pattern: String,
-    #[serde(rename(deserialize = "apply-to"))]
+    #[serde(rename = "apply-to")]
     pub apply_to: PolicyTarget,
     pub priority: i16,
     pub definition: PolicyDefinition,
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[allow(dead_code)]
 pub struct Permissions {
     pub user: String,
@@ -349,10 +762,57 @@ pub struct Permissions {
     pub write: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[allow(dead_code)]
+pub struct TopicPermissions {
+    pub user: String,
+    pub vhost: String,
+    pub exchange: String,
+    pub read: String,
+    pub write: String,
+}
+
+/// Everything the HTTP API knows about a single user account:
+/// the user record, its permissions and topic permissions across
+/// all virtual hosts, and its limits.
+#[derive(Debug, Clone)]
+pub struct UserExport {
+    pub user: User,
+    pub permissions: Vec<Permissions>,
+    pub topic_permissions: Vec<TopicPermissions>,
+    pub limits: EnforcedLimits,
+}
+
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 pub enum HealthCheckFailureDetails {
     AlarmCheck(ClusterAlarmCheckDetails),
     NodeIsQuorumCritical(QuorumCriticalityCheckDetails),
+    QueueCountLimitReached(QueueCountCheckDetails),
+    AlivenessCheck(AlivenessCheckDetails),
+    VirtualHostsDown(VirtualHostsCheckDetails),
+    NodeNotInService(NodeInServiceCheckDetails),
+    NoActiveProtocolListener(ProtocolListenerCheckDetails),
+    NoActivePortListener(PortListenerCheckDetails),
+    CertificatesExpiring(CertificateExpirationCheckDetails),
+}
+
+impl HealthCheckFailureDetails {
+    /// The reason the health check failed, as reported by the node.
+    pub fn reason(&self) -> &str {
+        match self {
+            HealthCheckFailureDetails::AlarmCheck(details) => &details.reason,
+            HealthCheckFailureDetails::NodeIsQuorumCritical(details) => &details.reason,
+            HealthCheckFailureDetails::QueueCountLimitReached(details) => &details.reason,
+            HealthCheckFailureDetails::AlivenessCheck(details) => {
+                details.reason.as_deref().unwrap_or(&details.status)
+            }
+            HealthCheckFailureDetails::VirtualHostsDown(details) => &details.reason,
+            HealthCheckFailureDetails::NodeNotInService(details) => &details.reason,
+            HealthCheckFailureDetails::NoActiveProtocolListener(details) => &details.reason,
+            HealthCheckFailureDetails::NoActivePortListener(details) => &details.reason,
+            HealthCheckFailureDetails::CertificatesExpiring(details) => &details.reason,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
@@ -364,7 +824,7 @@ pub struct ClusterAlarmCheckDetails {
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 pub struct ResourceAlarm {
     pub node: String,
-    pub resource: String,
+    pub resource: AlarmResource,
 }
 
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
@@ -379,5 +839,575 @@ pub struct QuorumEndangeredQueue {
     #[serde(rename(deserialize = "virtual_host"))]
     pub vhost: String,
     #[serde(rename(deserialize = "type"))]
-    pub queue_type: String,
+    pub queue_type: QueueType,
+}
+
+/// The virtual hosts that are not running on the node that performed the check.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct VirtualHostsCheckDetails {
+    pub reason: String,
+    #[serde(default, rename(deserialize = "virtual-hosts"))]
+    pub virtual_hosts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct NodeInServiceCheckDetails {
+    pub reason: String,
+}
+
+/// The protocol that has no active listener and the protocols that do.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct ProtocolListenerCheckDetails {
+    pub reason: String,
+    pub missing: String,
+    #[serde(default)]
+    pub protocols: Vec<String>,
+}
+
+/// The port that has no active listener and the ports that do.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct PortListenerCheckDetails {
+    pub reason: String,
+    pub missing: u16,
+    #[serde(default)]
+    pub ports: Vec<u16>,
+}
+
+/// The TLS-enabled listeners whose certificates expire within the checked window.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct CertificateExpirationCheckDetails {
+    pub reason: String,
+    #[serde(default)]
+    pub expired: Vec<ExpiringCertificate>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct ExpiringCertificate {
+    pub node: String,
+    pub protocol: String,
+    pub interface: String,
+    pub port: u16,
+    pub certfile: String,
+    #[serde(default)]
+    pub cacertfile: Option<String>,
+    /// When the certificate expires, for example, `2026-11-02T12:00:00+00:00`
+    pub expiry_date: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct AlivenessCheckDetails {
+    pub status: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct QueueCountCheckDetails {
+    pub reason: String,
+    pub limit: usize,
+    pub virtual_hosts: Vec<VirtualHostQueueCount>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct VirtualHostQueueCount {
+    pub vhost: String,
+    pub queue_count: usize,
+}
+
+/// The `max-connections` limit of a virtual host and the connections counted against it,
+/// see `Client::list_connection_tracking`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConnectionTracking {
+    pub vhost: String,
+    /// The `max-connections` limit, if one is set. `0` means no connections are allowed.
+    pub max_connections: Option<i64>,
+    pub connection_count: usize,
+    /// Connection counts per node the clients are connected to
+    pub connections_per_node: BTreeMap<String, usize>,
+}
+
+impl ConnectionTracking {
+    /// Returns `true` if there are more connections than the limit allows.
+    ///
+    /// RabbitMQ refuses connections over the limit, so this usually means
+    /// that connection tracking is disabled on some nodes.
+    pub fn is_over_limit(&self) -> bool {
+        self.max_connections
+            .is_some_and(|max| max >= 0 && self.connection_count as i64 > max)
+    }
+}
+
+/// Differences between the topologies of two clusters, see `Client::compare_topology`.
+///
+/// "This" cluster is the one the comparison was performed on, "other" is the one passed as an argument.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TopologyDiff {
+    pub virtual_hosts_only_in_this: Vec<String>,
+    pub virtual_hosts_only_in_other: Vec<String>,
+    pub queues: ObjectDiff,
+    pub exchanges: ObjectDiff,
+    pub bindings: ObjectDiff,
+    pub policies: ObjectDiff,
+}
+
+impl TopologyDiff {
+    /// Returns `true` if the two topologies are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.virtual_hosts_only_in_this.is_empty()
+            && self.virtual_hosts_only_in_other.is_empty()
+            && self.queues.is_empty()
+            && self.exchanges.is_empty()
+            && self.bindings.is_empty()
+            && self.policies.is_empty()
+    }
+}
+
+/// Differences between the objects of one kind, keyed by `(virtual host, name)`.
+///
+/// Bindings do not have names, so they are identified by a name of the form
+/// `source -> destination_type destination (properties_key)`. The properties key
+/// is derived from the routing key and the arguments, so a binding declared with
+/// different properties has a different name: bindings only ever appear in
+/// `only_in_this` and `only_in_other`, never in `different`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ObjectDiff {
+    pub only_in_this: Vec<(String, String)>,
+    pub only_in_other: Vec<(String, String)>,
+    /// Objects that exist on both sides but were declared with different properties
+    pub different: Vec<(String, String)>,
+}
+
+impl ObjectDiff {
+    pub fn is_empty(&self) -> bool {
+        self.only_in_this.is_empty() && self.only_in_other.is_empty() && self.different.is_empty()
+    }
+}
+
+/// Queues and exchanges with the bindings between them, see `Client::topology_snapshot`.
+///
+/// The default exchange is included under the name `""`. RabbitMQ reports its implicit
+/// binding to every queue, so every queue has at least one inbound binding.
+#[derive(Debug, Clone)]
+pub struct TopologySnapshot {
+    pub queues: Vec<QueueWithBindings>,
+    pub exchanges: Vec<ExchangeWithBindings>,
+}
+
+impl TopologySnapshot {
+    pub fn find_queue(&self, vhost: &str, name: &str) -> Option<&QueueWithBindings> {
+        self.queues
+            .iter()
+            .find(|q| q.queue.vhost == vhost && q.queue.name == name)
+    }
+
+    pub fn find_exchange(&self, vhost: &str, name: &str) -> Option<&ExchangeWithBindings> {
+        self.exchanges
+            .iter()
+            .find(|x| x.exchange.vhost == vhost && x.exchange.name == name)
+    }
+}
+
+/// A queue and the bindings that route messages to it.
+#[derive(Debug, Clone)]
+pub struct QueueWithBindings {
+    pub queue: QueueInfo,
+    pub bindings: Vec<BindingInfo>,
+}
+
+/// An exchange and the bindings it routes messages over, to queues as well as to other exchanges.
+#[derive(Debug, Clone)]
+pub struct ExchangeWithBindings {
+    pub exchange: ExchangeInfo,
+    pub bindings: Vec<BindingInfo>,
+}
+
+/// Definitions as exported by `GET /api/definitions`.
+///
+/// Every section is optional in the source document and defaults to an empty list.
+/// Sections this type does not model, such as topic permissions, are dropped.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+#[allow(dead_code)]
+pub struct Definitions {
+    #[serde(rename = "rabbitmq_version", skip_serializing_if = "Option::is_none")]
+    pub server_version: Option<String>,
+    pub vhosts: Vec<VirtualHostDefinition>,
+    pub users: Vec<UserDefinition>,
+    pub permissions: Vec<Permissions>,
+    pub parameters: Vec<RuntimeParameter>,
+    pub global_parameters: Vec<GlobalRuntimeParameter>,
+    pub policies: Vec<Policy>,
+    pub queues: Vec<QueueDefinition>,
+    pub exchanges: Vec<ExchangeDefinition>,
+    pub bindings: Vec<BindingDefinition>,
+}
+
+impl Definitions {
+    /// Returns a definitions document that can be passed to `Client::import_definitions`.
+    pub fn to_json(&self) -> serde_json::Value {
+        json!(self)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct VirtualHostDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub metadata: VirtualHostMetadata,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct UserDefinition {
+    pub name: String,
+    pub password_hash: String,
+    #[serde(default)]
+    pub hashing_algorithm: String,
+    /// Older versions export tags as a comma-separated string
+    #[serde(default, deserialize_with = "deserialize_list_or_comma_separated")]
+    pub tags: Vec<String>,
+}
+
+/// A cluster-wide runtime parameter, such as `cluster_name`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct GlobalRuntimeParameter {
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+/// Cluster tags, such as `region` or `environment`, stored in the `cluster_tags` global parameter.
+pub type ClusterTags = Map<String, serde_json::Value>;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct QueueDefinition {
+    pub name: String,
+    pub vhost: String,
+    pub durable: bool,
+    pub auto_delete: bool,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct ExchangeDefinition {
+    pub name: String,
+    pub vhost: String,
+    #[serde(rename = "type")]
+    pub exchange_type: ExchangeType,
+    pub durable: bool,
+    pub auto_delete: bool,
+    #[serde(default)]
+    pub internal: bool,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[allow(dead_code)]
+pub struct BindingDefinition {
+    pub vhost: String,
+    pub source: String,
+    pub destination: String,
+    pub destination_type: BindingDestinationType,
+    pub routing_key: String,
+    #[serde(default)]
+    pub arguments: XArguments,
+}
+
+/// What importing a set of definitions would do, computed without applying anything.
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub parameters: SectionPlan<RuntimeParameter>,
+    pub policies: SectionPlan<Policy>,
+    pub queues: SectionPlan<QueueDefinition>,
+    pub exchanges: SectionPlan<ExchangeDefinition>,
+    pub bindings: SectionPlan<BindingDefinition>,
+}
+
+/// Objects of one definition section, grouped by the effect an import would have on them.
+#[derive(Debug, Clone)]
+pub struct SectionPlan<T> {
+    /// Objects that do not exist yet
+    pub to_create: Vec<T>,
+    /// Objects that exist but whose definition differs and that an import would update.
+    /// Only used for runtime parameters and policies.
+    pub to_update: Vec<T>,
+    /// Objects that exist but whose definition differs and that an import cannot change.
+    /// Only used for queues and exchanges: the broker refuses to redeclare them
+    /// with different properties, so importing them fails.
+    pub conflicting: Vec<T>,
+    /// Objects that exist with an identical definition
+    pub unchanged: Vec<T>,
+}
+
+impl<T> Default for SectionPlan<T> {
+    fn default() -> Self {
+        Self {
+            to_create: Vec::new(),
+            to_update: Vec::new(),
+            conflicting: Vec::new(),
+            unchanged: Vec::new(),
+        }
+    }
+}
+
+impl<T> SectionPlan<T> {
+    pub fn has_changes(&self) -> bool {
+        !self.to_create.is_empty() || !self.to_update.is_empty()
+    }
+
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicting.is_empty()
+    }
+}
+
+impl ImportPlan {
+    /// Compares the `desired` definitions against the `current` ones.
+    ///
+    /// Queues, exchanges and policies are identified by virtual host and name,
+    /// runtime parameters by virtual host, component and name. Bindings have
+    /// no identity besides their full definition, so they are either created or unchanged.
+    /// Queues and exchanges that differ are reported as conflicting rather than to be updated.
+    pub fn compute(desired: &Definitions, current: &Definitions) -> Self {
+        Self {
+            parameters: plan_section(&desired.parameters, &current.parameters, true, |p| {
+                (p.vhost.clone(), p.component.clone(), p.name.clone())
+            }),
+            policies: plan_section(&desired.policies, &current.policies, true, |p| {
+                (p.vhost.clone(), p.name.clone())
+            }),
+            queues: plan_section(&desired.queues, &current.queues, false, |q| {
+                (q.vhost.clone(), q.name.clone())
+            }),
+            exchanges: plan_section(&desired.exchanges, &current.exchanges, false, |x| {
+                (x.vhost.clone(), x.name.clone())
+            }),
+            bindings: plan_section(&desired.bindings, &current.bindings, false, |b| {
+                (
+                    b.vhost.clone(),
+                    b.source.clone(),
+                    b.destination.clone(),
+                    String::from(b.destination_type.clone()),
+                    b.routing_key.clone(),
+                    serde_json::to_string(&b.arguments).unwrap_or_default(),
+                )
+            }),
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.parameters.has_changes()
+            || self.policies.has_changes()
+            || self.queues.has_changes()
+            || self.exchanges.has_changes()
+            || self.bindings.has_changes()
+    }
+
+    /// Returns `true` if importing would fail because of queues or exchanges
+    /// that exist with a different definition.
+    pub fn has_conflicts(&self) -> bool {
+        self.queues.has_conflicts() || self.exchanges.has_conflicts()
+    }
+}
+
+/// `updatable` tells whether an import can change an existing object of this section.
+fn plan_section<T, K, F>(desired: &[T], current: &[T], updatable: bool, key: F) -> SectionPlan<T>
+where
+    T: Clone + PartialEq,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let existing: HashMap<K, &T> = current.iter().map(|it| (key(it), it)).collect();
+    let mut plan = SectionPlan::default();
+    for it in desired {
+        match existing.get(&key(it)) {
+            None => plan.to_create.push(it.clone()),
+            Some(found) if *found == it => plan.unchanged.push(it.clone()),
+            Some(_) if updatable => plan.to_update.push(it.clone()),
+            Some(_) => plan.conflicting.push(it.clone()),
+        }
+    }
+    plan
+}
+
+/// Deserializes an API response, tolerating values that do not match the expected types.
+///
+/// Values that fail to deserialize are removed one at a time, so that optional fields
+/// fall back to their defaults. If the object that contained a removed value can no longer
+/// be deserialized, it is removed from its parent in turn. The removed values are logged
+/// as a single warning. Used by clients built with `with_lenient_deserialization(true)`.
+///
+/// Use [`deserialize_list_leniently`] for lists, which handles every element on its own.
+///
+/// Example
+/// ```rust
+/// use rabbitmq_http_client::responses::{self, VirtualHost};
+/// use serde_json::json;
+///
+/// // the description is expected to be a string
+/// let value = json!({"name": "/", "description": 42, "metadata": {}});
+/// let vhost: VirtualHost = responses::deserialize_leniently(value).unwrap();
+/// assert_eq!(vhost.name, "/");
+/// assert!(vhost.description.is_none());
+/// ```
+pub fn deserialize_leniently<T: DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<T, serde_json::Error> {
+    let mut ignored = BTreeSet::new();
+    let result = repair_and_deserialize(value, &mut ignored);
+    if !ignored.is_empty() {
+        log::warn!(
+            "ignored values that could not be deserialized in the API response: {}",
+            ignored.into_iter().collect::<Vec<_>>().join(", ")
+        );
+    }
+    result
+}
+
+/// Deserializes a list API response element by element, see [`deserialize_leniently`].
+///
+/// Elements that cannot be deserialized even without the offending values are skipped.
+/// The ignored values and the number of skipped elements are logged as a single warning.
+/// Fails if the response is not a list, or if no element of a non-empty list
+/// could be deserialized.
+///
+/// Example
+/// ```rust
+/// use rabbitmq_http_client::responses::{self, VirtualHost};
+/// use serde_json::json;
+///
+/// // the second element lacks the required name
+/// let value = json!([{"name": "/", "metadata": {}}, {"metadata": {}}]);
+/// let vhosts: Vec<VirtualHost> = responses::deserialize_list_leniently(value).unwrap();
+/// assert_eq!(vhosts.len(), 1);
+/// ```
+pub fn deserialize_list_leniently<T: DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<Vec<T>, serde_json::Error> {
+    let items: Vec<serde_json::Value> = serde_json::from_value(value)?;
+    let total = items.len();
+    let mut ignored = BTreeSet::new();
+    let mut first_error = None;
+    let mut result = Vec::with_capacity(total);
+    for item in items {
+        match repair_and_deserialize(item, &mut ignored) {
+            Ok(it) => result.push(it),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+
+    if let (true, Some(err)) = (result.is_empty(), first_error) {
+        return Err(err);
+    }
+    let skipped = total - result.len();
+    if !ignored.is_empty() || skipped > 0 {
+        log::warn!(
+            "ignored values that could not be deserialized in the API response ({}) and skipped {} of {} elements",
+            ignored.into_iter().collect::<Vec<_>>().join(", "),
+            skipped,
+            total
+        );
+    }
+    Ok(result)
+}
+
+/// Removes the values of `value` that fail to deserialize until it deserializes,
+/// recording the paths of the removed values in `ignored`.
+fn repair_and_deserialize<T: DeserializeOwned>(
+    mut value: serde_json::Value,
+    ignored: &mut BTreeSet<String>,
+) -> Result<T, serde_json::Error> {
+    loop {
+        let err = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(result) => return Ok(result),
+            Err(err) => err,
+        };
+        let segments: Vec<&Segment> = err.path().iter().collect();
+        let removed = match segments.split_last() {
+            Some((last, parents)) => remove_value(&mut value, parents, last),
+            None => false,
+        };
+        if !removed {
+            return Err(err.into_inner());
+        }
+        ignored.insert(err.path().to_string());
+    }
+}
+
+/// Removes the value at `parents` + `last`, returns `false` if there is no such value.
+fn remove_value(value: &mut serde_json::Value, parents: &[&Segment], last: &Segment) -> bool {
+    let mut parent = value;
+    for segment in parents {
+        let child = match segment {
+            Segment::Seq { index } => parent.get_mut(*index),
+            Segment::Map { key } => parent.get_mut(key.as_str()),
+            _ => None,
+        };
+        match child {
+            Some(child) => parent = child,
+            None => return false,
+        }
+    }
+
+    match (last, parent) {
+        (Segment::Seq { index }, serde_json::Value::Array(items)) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        (Segment::Map { key }, serde_json::Value::Object(fields)) => fields.remove(key).is_some(),
+        _ => false,
+    }
+}
+
+fn deserialize_optional_queue_type<'de, D>(deserializer: D) -> Result<Option<QueueType>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.map(QueueType::from))
+}
+
+fn deserialize_list_or_comma_separated<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ListOrString {
+        List(Vec<String>),
+        String(String),
+    }
+
+    Ok(match ListOrString::deserialize(deserializer)? {
+        ListOrString::List(items) => items,
+        ListOrString::String(s) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_owned)
+            .collect(),
+    })
+}
+
+/// Some RabbitMQ versions serialize an empty map as `[]` instead of `{}`.
+fn deserialize_map_or_empty_seq<'de, D>(
+    deserializer: D,
+) -> Result<Map<String, serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Object(map) => Ok(map),
+        serde_json::Value::Array(items) if items.is_empty() => Ok(Map::new()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a map or an empty list, got {}",
+            other
+        ))),
+    }
 }
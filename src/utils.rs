@@ -0,0 +1,433 @@
+//! Helpers shared by the blocking and the async client.
+
+use crate::{
+    commons::{self, BindingDestinationType, ExchangeType},
+    requests, responses,
+};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION, LOCATION},
+    Url,
+};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Display,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub(crate) fn percent_encode(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+/// Builds the URL of an API endpoint, inserting the optional cluster prefix
+/// right after the endpoint's host.
+pub(crate) fn rooted_path(endpoint: &str, path_prefix: Option<&str>, path: &str) -> String {
+    match path_prefix {
+        None => format!("{}/{}", endpoint, path),
+        Some(prefix) => {
+            let (base, endpoint_path) = split_endpoint(endpoint);
+            format!("{}/{}{}/{}", base, prefix, endpoint_path, path)
+        }
+    }
+}
+
+/// Splits an endpoint such as `https://host:15672/api` into
+/// the scheme and authority (`https://host:15672`) and the path (`/api`).
+fn split_endpoint(endpoint: &str) -> (&str, &str) {
+    let authority_start = endpoint.find("://").map_or(0, |i| i + 3);
+    match endpoint[authority_start..].find('/') {
+        Some(i) => endpoint.split_at(authority_start + i),
+        None => (endpoint, ""),
+    }
+}
+
+/// Returns a copy of `url` without any credentials in it.
+pub(crate) fn redacted_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url
+}
+
+/// Returns a copy of `headers` without the `Authorization` header.
+pub(crate) fn redacted_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    headers.remove(AUTHORIZATION);
+    headers
+}
+
+/// Returns the properties key of a binding from the `Location` header of the response
+/// that created it, such as `/api/bindings/%2F/e/events/q/audit/orders.%23`.
+pub(crate) fn binding_properties_key(headers: &HeaderMap) -> Option<String> {
+    let location = headers.get(LOCATION)?.to_str().ok()?;
+    let encoded = location.rsplit('/').next()?;
+    percent_decode_str(encoded)
+        .decode_utf8()
+        .ok()
+        .map(|key| key.into_owned())
+}
+
+/// Follows `x-alternate-exchange` arguments starting at `exchange`.
+/// Returns the chain collected so far as the error if it loops back on itself.
+pub(crate) fn alternate_exchange_chain(
+    exchanges: Vec<responses::ExchangeInfo>,
+    exchange: &str,
+) -> Result<Vec<String>, Vec<String>> {
+    let alternates: HashMap<String, String> = exchanges
+        .into_iter()
+        .filter_map(|x| {
+            x.arguments
+                .get("x-alternate-exchange")
+                .and_then(|v| v.as_str())
+                .map(|ae| (x.name, ae.to_owned()))
+        })
+        .collect();
+
+    let mut chain: Vec<String> = Vec::new();
+    let mut current = exchange;
+    while let Some(next) = alternates.get(current) {
+        let seen = next == exchange || chain.contains(next);
+        chain.push(next.clone());
+        if seen {
+            return Err(chain);
+        }
+        current = next;
+    }
+    Ok(chain)
+}
+
+/// Returns `true` if an exchange of the given type routes a message
+/// with `routing_key` over a binding with `binding_key`.
+pub(crate) fn routes_over_binding(
+    exchange_type: &ExchangeType,
+    binding_key: &str,
+    routing_key: &str,
+) -> bool {
+    match exchange_type {
+        ExchangeType::Fanout => true,
+        ExchangeType::Direct => binding_key == routing_key,
+        ExchangeType::Topic => commons::topic_pattern_matches(binding_key, routing_key),
+        _ => false,
+    }
+}
+
+/// Returns the virtual hosts that have `limit` or more queues, if any.
+pub(crate) fn queue_count_check_details(
+    queues: Vec<responses::QueueInfo>,
+    limit: usize,
+) -> Option<responses::QueueCountCheckDetails> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for q in queues {
+        *counts.entry(q.vhost).or_insert(0) += 1;
+    }
+
+    let offenders: Vec<responses::VirtualHostQueueCount> = counts
+        .into_iter()
+        .filter(|(_, n)| *n >= limit)
+        .map(|(vhost, queue_count)| responses::VirtualHostQueueCount { vhost, queue_count })
+        .collect();
+    if offenders.is_empty() {
+        return None;
+    }
+
+    Some(responses::QueueCountCheckDetails {
+        reason: format!("some virtual hosts have {} or more queues", limit),
+        limit,
+        virtual_hosts: offenders,
+    })
+}
+
+/// Returns the names of the properties in which an existing queue differs from a declaration.
+/// Differing arguments are reported as `arguments.<name>`.
+pub(crate) fn queue_differing_fields(
+    existing: &responses::QueueInfo,
+    requested: &requests::QueueParams<'_>,
+) -> Vec<String> {
+    let mut fields = Vec::new();
+    if existing.queue_type != requested.queue_type {
+        fields.push("type".to_owned());
+    }
+    if existing.durable != requested.durable {
+        fields.push("durable".to_owned());
+    }
+    if existing.auto_delete != requested.auto_delete {
+        fields.push("auto_delete".to_owned());
+    }
+    if existing.exclusive != requested.exclusive {
+        fields.push("exclusive".to_owned());
+    }
+
+    let empty = serde_json::Map::new();
+    let requested_args = requested.arguments.as_ref().unwrap_or(&empty);
+    let keys: BTreeSet<&String> = existing
+        .arguments
+        .keys()
+        .chain(requested_args.keys())
+        // compared as the queue type above
+        .filter(|k| k.as_str() != "x-queue-type")
+        .collect();
+    for k in keys {
+        let same = match (existing.arguments.get(k), requested_args.get(k)) {
+            (Some(a), Some(b)) => responses::equivalent_values(a, b),
+            _ => false,
+        };
+        if !same {
+            fields.push(format!("arguments.{}", k));
+        }
+    }
+    fields
+}
+
+pub(crate) fn connection_tracking(
+    vhost: &str,
+    limits: Vec<responses::VirtualHostLimits>,
+    connections: Vec<responses::Connection>,
+) -> responses::ConnectionTracking {
+    let max_connections = limits
+        .iter()
+        .find_map(|l| l.limits.get("max-connections").and_then(|v| v.as_i64()));
+    let mut connections_per_node: BTreeMap<String, usize> = BTreeMap::new();
+    for c in &connections {
+        *connections_per_node.entry(c.node.clone()).or_insert(0) += 1;
+    }
+
+    responses::ConnectionTracking {
+        vhost: vhost.to_owned(),
+        max_connections,
+        connection_count: connections.len(),
+        connections_per_node,
+    }
+}
+
+/// The objects fetched from one cluster by `Client::compare_topology`.
+pub(crate) struct Topology {
+    pub(crate) vhosts: Vec<responses::VirtualHost>,
+    pub(crate) queues: Vec<responses::QueueInfo>,
+    pub(crate) exchanges: Vec<responses::ExchangeInfo>,
+    pub(crate) bindings: Vec<responses::BindingInfo>,
+    pub(crate) policies: Vec<responses::Policy>,
+}
+
+pub(crate) fn topology_diff(this: Topology, other: Topology) -> responses::TopologyDiff {
+    let this_vhosts: BTreeSet<String> = this.vhosts.into_iter().map(|vh| vh.name).collect();
+    let other_vhosts: BTreeSet<String> = other.vhosts.into_iter().map(|vh| vh.name).collect();
+
+    responses::TopologyDiff {
+        virtual_hosts_only_in_this: this_vhosts.difference(&other_vhosts).cloned().collect(),
+        virtual_hosts_only_in_other: other_vhosts.difference(&this_vhosts).cloned().collect(),
+        queues: object_diff(
+            this.queues,
+            other.queues,
+            |q| (q.vhost.clone(), q.name.clone()),
+            |a, b| {
+                a.queue_type == b.queue_type
+                    && a.durable == b.durable
+                    && a.auto_delete == b.auto_delete
+                    && a.exclusive == b.exclusive
+                    && responses::equivalent_arguments(&a.arguments, &b.arguments)
+            },
+        ),
+        exchanges: object_diff(
+            this.exchanges,
+            other.exchanges,
+            |x| (x.vhost.clone(), x.name.clone()),
+            |a, b| {
+                a.exchange_type == b.exchange_type
+                    && a.durable == b.durable
+                    && a.auto_delete == b.auto_delete
+                    && responses::equivalent_arguments(&a.arguments, &b.arguments)
+            },
+        ),
+        bindings: object_diff(
+            this.bindings,
+            other.bindings,
+            |b| {
+                let name = format!(
+                    "{} -> {} {} ({})",
+                    b.source, b.destination_type, b.destination, b.properties_key
+                );
+                (b.vhost.clone(), name)
+            },
+            // the properties key is derived from the routing key and the arguments
+            |_, _| true,
+        ),
+        policies: object_diff(
+            this.policies,
+            other.policies,
+            |p| (p.vhost.clone(), p.name.clone()),
+            |a, b| {
+                a.pattern == b.pattern
+                    && a.apply_to == b.apply_to
+                    && a.priority == b.priority
+                    && a.definition == b.definition
+            },
+        ),
+    }
+}
+
+/// Groups bindings by the queue they route to and by the exchange they route from.
+/// Objects are matched by virtual host and name.
+pub(crate) fn topology_snapshot(
+    queues: Vec<responses::QueueInfo>,
+    exchanges: Vec<responses::ExchangeInfo>,
+    bindings: Vec<responses::BindingInfo>,
+) -> responses::TopologySnapshot {
+    let mut inbound: HashMap<(String, String), Vec<responses::BindingInfo>> = HashMap::new();
+    let mut outbound: HashMap<(String, String), Vec<responses::BindingInfo>> = HashMap::new();
+    for b in bindings {
+        outbound
+            .entry((b.vhost.clone(), b.source.clone()))
+            .or_default()
+            .push(b.clone());
+        if b.destination_type == BindingDestinationType::Queue {
+            inbound
+                .entry((b.vhost.clone(), b.destination.clone()))
+                .or_default()
+                .push(b);
+        }
+    }
+
+    responses::TopologySnapshot {
+        queues: queues
+            .into_iter()
+            .map(|queue| {
+                let key = (queue.vhost.clone(), queue.name.clone());
+                let bindings = inbound.remove(&key).unwrap_or_default();
+                responses::QueueWithBindings { queue, bindings }
+            })
+            .collect(),
+        exchanges: exchanges
+            .into_iter()
+            .map(|exchange| {
+                let key = (exchange.vhost.clone(), exchange.name.clone());
+                let bindings = outbound.remove(&key).unwrap_or_default();
+                responses::ExchangeWithBindings { exchange, bindings }
+            })
+            .collect(),
+    }
+}
+
+fn object_diff<T>(
+    this: Vec<T>,
+    other: Vec<T>,
+    key: impl Fn(&T) -> (String, String),
+    equivalent: impl Fn(&T, &T) -> bool,
+) -> responses::ObjectDiff {
+    let mut remaining: BTreeMap<(String, String), T> =
+        other.into_iter().map(|o| (key(&o), o)).collect();
+    let this: BTreeMap<(String, String), T> = this.into_iter().map(|o| (key(&o), o)).collect();
+
+    let mut diff = responses::ObjectDiff::default();
+    for (k, a) in this {
+        match remaining.remove(&k) {
+            None => diff.only_in_this.push(k),
+            Some(b) if !equivalent(&a, &b) => diff.different.push(k),
+            Some(_) => {}
+        }
+    }
+    diff.only_in_other = remaining.into_keys().collect();
+    diff
+}
+
+/// The only field requested when listing objects with `columns=name`.
+#[derive(Deserialize)]
+pub(crate) struct Named {
+    pub(crate) name: String,
+}
+
+/// The only field requested from `GET /api/overview` with `columns=sample_retention_policies`.
+#[derive(Deserialize)]
+pub(crate) struct OverviewSampleRetentionPolicies {
+    pub(crate) sample_retention_policies: responses::SampleRetentionPolicies,
+}
+
+/// The only field requested from `GET /api/overview` with `columns=rabbitmq_version`.
+#[derive(Deserialize)]
+pub(crate) struct OverviewVersion {
+    pub(crate) rabbitmq_version: String,
+}
+
+/// The response of `POST /api/exchanges/{vhost}/{exchange}/publish`.
+#[derive(Deserialize)]
+pub(crate) struct PublishResult {
+    pub(crate) routed: bool,
+}
+
+/// The name of the global parameter cluster tags are stored in.
+pub(crate) const CLUSTER_TAGS_PARAMETER: &str = "cluster_tags";
+
+/// Parses the value of the `cluster_tags` global parameter.
+/// Tags set in the configuration file are reported as an object,
+/// or as an empty list if there are none.
+pub(crate) fn cluster_tags(value: serde_json::Value) -> responses::ClusterTags {
+    match value {
+        serde_json::Value::Object(tags) => tags,
+        serde_json::Value::Array(pairs) => pairs
+            .into_iter()
+            .filter_map(|pair| match pair {
+                serde_json::Value::Array(mut kv) if kv.len() == 2 => {
+                    let v = kv.pop()?;
+                    let k = kv.pop()?;
+                    k.as_str().map(|k| (k.to_owned(), v))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => responses::ClusterTags::new(),
+    }
+}
+
+/// How long a queue declared by `Client::declare_trace_consumer_queue` can be unused
+/// before it is deleted.
+pub(crate) const TRACE_QUEUE_EXPIRES: Duration = Duration::from_secs(30 * 60);
+
+/// How often `Client::shovel_messages_once` checks whether its shovel has finished.
+pub(crate) const SHOVEL_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The URI of a direct connection to the local node and the given virtual host,
+/// as used in shovel definitions.
+pub(crate) fn local_amqp_uri(vhost: &str) -> String {
+    format!("amqp:///{}", percent_encode(vhost))
+}
+
+/// Returns the name of a temporary shovel that moves messages out of `queue`.
+pub(crate) fn one_off_shovel_name(queue: &str) -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    format!("{}.moved-by-rabbitmq-http-client.{}", queue, millis)
+}
+
+/// Returns `true` if a RabbitMQ version such as `3.13.1`, `v3.13.1` or `4.0.0-rc.1`
+/// is `major.minor` or later. Versions that cannot be parsed are treated as `0.0`.
+pub(crate) fn version_at_least(version: &str, major: u32, minor: u32) -> bool {
+    let mut parts = version
+        .trim_start_matches('v')
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let actual = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    actual >= (major, minor)
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum HttpVersion {
+    Negotiated,
+    Http1Only,
+    Http2PriorKnowledge,
+}
+
+pub(crate) enum BindindVertex {
+    Source,
+    Destination,
+}
+
+impl Display for BindindVertex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Source => write!(f, "source"),
+            Self::Destination => write!(f, "destination"),
+        }
+    }
+}
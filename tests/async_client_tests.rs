@@ -0,0 +1,91 @@
+use rabbitmq_http_client::{
+    api::{Client, Error},
+    commons::QueueType,
+    requests::{QueueParams, VirtualHostParams},
+};
+
+mod common;
+use crate::common::{endpoint, PASSWORD, USERNAME};
+
+#[tokio::test]
+async fn test_async_list_nodes() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let result = rc.list_nodes().await;
+
+    assert!(result.is_ok(), "list_nodes returned {:?}", result);
+    assert!(!result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_async_create_and_delete_vhost() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let name = "rust_test_async_create_and_delete_vhost";
+
+    let _ = rc.delete_vhost(name).await;
+
+    let params = VirtualHostParams {
+        name,
+        description: None,
+        tags: None,
+        default_queue_type: Some(QueueType::Classic),
+        tracing: false,
+    };
+    let result1 = rc.create_vhost(&params).await;
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let result2 = rc.get_vhost(name).await;
+    assert!(result2.is_ok(), "get_vhost returned {:?}", result2);
+    assert_eq!(result2.unwrap().name, name);
+
+    let result3 = rc.delete_vhost(name).await;
+    assert!(result3.is_ok(), "delete_vhost returned {:?}", result3);
+}
+
+#[tokio::test]
+async fn test_async_declare_and_get_queue() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.async.cq.69373293479827";
+
+    let _ = rc.delete_queue(vhost, name).await;
+
+    let params = QueueParams::new_durable_classic_queue(name, None);
+    let result1 = rc.declare_queue(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.get_queue_info(vhost, name).await;
+    assert!(result2.is_ok(), "get_queue_info returned {:?}", result2);
+    assert_eq!(result2.unwrap().name, name);
+
+    let _ = rc.delete_queue(vhost, name).await;
+}
+
+#[tokio::test]
+async fn test_async_authentication_failure() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, "not-the-password");
+    let result = rc.list_nodes().await;
+
+    assert!(matches!(result, Err(Error::AuthenticationFailed(_))));
+}
+
+#[tokio::test]
+async fn test_async_export_definitions_to_writer() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = rc.export_definitions_to_writer(&mut buf).await;
+    assert!(
+        result.is_ok(),
+        "export_definitions_to_writer returned {:?}",
+        result
+    );
+    assert_eq!(result.unwrap(), buf.len() as u64);
+
+    let defs: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert!(defs.get("queues").is_some());
+}
@@ -1,11 +1,14 @@
 use rabbitmq_http_client::{
     blocking::Client,
     commons::BindingDestinationType,
-    requests::{ExchangeParams, QueueParams},
+    requests::{BindingSpec, ExchangeParams, QueueParams},
+    responses::BindingInfo,
 };
 
+use serde_json::json;
+
 mod common;
-use crate::common::{endpoint, PASSWORD, USERNAME};
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_all_bindings() {
@@ -191,6 +194,37 @@ fn test_delete_queue_bindings() {
     let _ = rc.delete_queue(vh_name, cq);
 }
 
+#[test]
+fn test_delete_queue_binding_without_arguments() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.delete_queue_binding_without_arguments";
+    let fanout = "amq.fanout";
+
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.bind_queue(vh_name, cq, fanout, Some("foo"), None);
+    assert!(result2.is_ok(), "bind_queue returned {:?}", result2);
+
+    let result3 = rc.delete_binding(
+        vh_name,
+        fanout,
+        cq,
+        BindingDestinationType::Queue,
+        "foo",
+        None,
+    );
+    assert!(result3.is_ok(), "delete_binding returned {:?}", result3);
+
+    let vec = rc.list_queue_bindings(vh_name, cq).unwrap();
+    assert!(!vec.iter().any(|b| b.source == fanout));
+
+    let _ = rc.delete_queue(vh_name, cq);
+}
+
 #[test]
 fn test_delete_exchange_bindings() {
     let endpoint = endpoint();
@@ -242,3 +276,312 @@ fn test_delete_exchange_bindings() {
             && b.destination == direct
             && b.source == fanout));
 }
+
+#[test]
+fn test_list_queue_bindings_excluding_default() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.durable.bindings_excluding_default";
+    let fanout = "amq.fanout";
+
+    let _ = rc.delete_queue(vh_name, cq);
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.bind_queue(vh_name, cq, fanout, None, None);
+    assert!(result2.is_ok(), "bind_queue returned {:?}", result2);
+
+    // the default exchange binding is included by the unfiltered listing
+    let all = rc.list_queue_bindings(vh_name, cq).unwrap();
+    assert!(all.iter().any(|b| b.source.is_empty()));
+
+    let result3 = rc.list_queue_bindings_excluding_default(vh_name, cq);
+    assert!(
+        result3.is_ok(),
+        "list_queue_bindings_excluding_default returned {:?}",
+        result3
+    );
+    let vec = result3.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].source, fanout);
+
+    let _ = rc.delete_queue(vh_name, cq);
+}
+
+#[test]
+fn test_find_and_delete_headers_binding() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.durable.headers_binding";
+    let headers = "amq.headers";
+
+    let _ = rc.delete_queue(vh_name, cq);
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let mut args = serde_json::Map::new();
+    args.insert("x-match".to_owned(), serde_json::json!("all"));
+    args.insert("priority".to_owned(), serde_json::json!(1));
+    args.insert("region".to_owned(), serde_json::json!("eu"));
+    let result2 = rc.bind_queue(vh_name, cq, headers, None, Some(args));
+    assert!(result2.is_ok(), "bind_queue returned {:?}", result2);
+
+    // same arguments, in a different order and with a different numeric representation
+    let mut lookup = serde_json::Map::new();
+    lookup.insert("region".to_owned(), serde_json::json!("eu"));
+    lookup.insert("priority".to_owned(), serde_json::json!(1.0));
+    lookup.insert("x-match".to_owned(), serde_json::json!("all"));
+
+    let result3 = rc.find_binding(
+        vh_name,
+        headers,
+        cq,
+        BindingDestinationType::Queue,
+        &|b: &BindingInfo| b.has_equivalent_arguments(&lookup),
+    );
+    assert!(result3.is_ok(), "find_binding returned {:?}", result3);
+    assert!(result3.unwrap().is_some());
+
+    let result4 = rc.delete_binding(
+        vh_name,
+        headers,
+        cq,
+        BindingDestinationType::Queue,
+        "",
+        Some(lookup),
+    );
+    assert!(result4.is_ok(), "delete_binding returned {:?}", result4);
+
+    let vec = rc
+        .list_queue_bindings_excluding_default(vh_name, cq)
+        .unwrap();
+    assert!(vec.is_empty());
+
+    let _ = rc.delete_queue(vh_name, cq);
+}
+
+#[test]
+fn test_routing_destinations() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let topic = "amq.topic";
+    let q1 = "rust.cq.routing_destinations.eu";
+    let q2 = "rust.cq.routing_destinations.all";
+    let fanout = "rust.x.routing_destinations.fanout";
+    let q3 = "rust.cq.routing_destinations.via_fanout";
+
+    for q in [q1, q2, q3] {
+        let _ = rc.delete_queue(vh_name, q);
+        rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(q, None))
+            .unwrap();
+    }
+    let _ = rc.delete_exchange(vh_name, fanout);
+    rc.declare_exchange(vh_name, &ExchangeParams::fanout(fanout, false, false, None))
+        .unwrap();
+
+    rc.bind_queue(vh_name, q1, topic, Some("orders.*.eu"), None)
+        .unwrap();
+    rc.bind_queue(vh_name, q2, topic, Some("orders.#"), None)
+        .unwrap();
+    rc.bind_exchange(vh_name, fanout, topic, Some("orders.created.*"), None)
+        .unwrap();
+    rc.bind_queue(vh_name, q3, fanout, None, None).unwrap();
+
+    let result1 = rc.routing_destinations(vh_name, topic, "orders.created.eu");
+    assert!(
+        result1.is_ok(),
+        "routing_destinations returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap(), vec![q2, q1, q3]);
+
+    let result2 = rc.routing_destinations(vh_name, topic, "orders.updated.us");
+    assert_eq!(result2.unwrap(), vec![q2]);
+
+    let result3 = rc.routing_destinations(vh_name, "", q1);
+    assert_eq!(result3.unwrap(), vec![q1]);
+
+    for q in [q1, q2, q3] {
+        let _ = rc.delete_queue(vh_name, q);
+    }
+    let _ = rc.delete_exchange(vh_name, fanout);
+}
+
+#[test]
+fn test_unbind_queue_from_exchange() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.unbind_queue_from_exchange";
+    let topic = "amq.topic";
+    let fanout = "amq.fanout";
+
+    let _ = rc.delete_queue(vh_name, cq);
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    for rk in ["orders.#", "invoices.*", "audit"] {
+        rc.bind_queue(vh_name, cq, topic, Some(rk), None).unwrap();
+    }
+    rc.bind_queue(vh_name, cq, fanout, None, None).unwrap();
+
+    let result2 = rc.unbind_queue_from_exchange(vh_name, cq, topic);
+    assert!(
+        result2.is_ok(),
+        "unbind_queue_from_exchange returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), 3);
+
+    let vec = rc.list_queue_bindings(vh_name, cq).unwrap();
+    assert!(!vec.iter().any(|b| b.source == topic));
+    assert!(vec.iter().any(|b| b.source == fanout));
+
+    let _ = rc.delete_queue(vh_name, cq);
+}
+
+#[test]
+fn test_declare_trace_consumer_queue() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vh_name = "/";
+    let name = "rust.tests.trace_consumer_queue";
+
+    let _ = rc.delete_queue(vh_name, name);
+
+    let result1 = rc.declare_trace_consumer_queue(vh_name, name);
+    assert!(
+        result1.is_ok(),
+        "declare_trace_consumer_queue returned {:?}",
+        result1
+    );
+
+    // declaring it again is a no-op
+    let result2 = rc.declare_trace_consumer_queue(vh_name, name);
+    assert!(
+        result2.is_ok(),
+        "declare_trace_consumer_queue returned {:?}",
+        result2
+    );
+
+    let queue = rc.get_queue_info(vh_name, name).unwrap();
+    assert!(queue.durable);
+    assert!(queue.auto_delete);
+    assert!(queue.arguments.contains_key("x-expires"));
+
+    let result3 = rc.list_queue_bindings(vh_name, name);
+    assert!(
+        result3.is_ok(),
+        "list_queue_bindings returned {:?}",
+        result3
+    );
+    assert!(result3
+        .unwrap()
+        .iter()
+        .any(|b| b.source == "amq.rabbitmq.trace" && b.routing_key == "#"));
+
+    rc.delete_queue(vh_name, name).unwrap();
+}
+
+#[test]
+fn test_bind_queues() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.tests.cq.bind_queues";
+    let _ = rc.delete_queue(vh_name, cq);
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let bindings = [
+        BindingSpec::new(cq, "amq.topic", Some("orders.#")),
+        BindingSpec::new(cq, "rust.tests.no_such_exchange", Some("orders.#")),
+        BindingSpec::new(cq, "amq.direct", Some("orders.eu")),
+    ];
+    let results = rc.bind_queues(vh_name, &bindings);
+    assert_eq!(results.len(), 3);
+    assert!(results[1].is_err());
+
+    // the broker quotes special characters in properties keys
+    let existing = rc.list_queue_bindings(vh_name, cq).unwrap();
+    let topic = existing.iter().find(|b| b.source == "amq.topic").unwrap();
+    assert_eq!(
+        results[0].as_ref().unwrap().as_deref(),
+        Some(topic.properties_key.as_str())
+    );
+    let direct = existing.iter().find(|b| b.source == "amq.direct").unwrap();
+    assert_eq!(
+        results[2].as_ref().unwrap().as_deref(),
+        Some(direct.properties_key.as_str())
+    );
+
+    let _ = rc.delete_queue(vh_name, cq);
+}
+
+#[test]
+fn test_bind_queues_without_location_header() {
+    // the fixture server does not send a Location header
+    let server = serve_fixtures(vec![(
+        "/api/bindings/%2F/e/events/q/orders",
+        "{}".to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let results = rc.bind_queues("/", &[BindingSpec::new("orders", "events", Some("new"))]);
+    assert_eq!(results.len(), 1);
+    assert!(
+        matches!(results[0], Ok(None)),
+        "bind_queues returned {:?}",
+        results
+    );
+}
+
+#[test]
+fn test_unbind_queue_from_exchange_counts_only_deleted_bindings() {
+    let binding = |source: &str, key: &str| {
+        json!({
+            "source": source,
+            "vhost": "/",
+            "destination": "orders",
+            "destination_type": "queue",
+            "routing_key": key,
+            "arguments": {},
+            "properties_key": key
+        })
+    };
+    let bindings = json!([
+        binding("events", "new"),
+        binding("events", "cancelled"),
+        binding("", "orders")
+    ]);
+    // there is no route for the "cancelled" binding, as if it was deleted concurrently
+    let server = serve_fixtures(vec![
+        ("/api/queues/%2F/orders/bindings", bindings.to_string()),
+        ("/api/bindings/%2F/e/events/q/orders/new", "{}".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.unbind_queue_from_exchange("/", "orders", "events");
+    assert!(
+        result.is_ok(),
+        "unbind_queue_from_exchange returned {:?}",
+        result
+    );
+    assert_eq!(result.unwrap(), 1);
+
+    let requests = server.requests();
+    assert!(requests.contains(&"DELETE /api/bindings/%2F/e/events/q/orders/cancelled".to_owned()));
+    // bindings of other exchanges are left alone
+    assert_eq!(
+        requests.iter().filter(|r| r.starts_with("DELETE")).count(),
+        2
+    );
+}
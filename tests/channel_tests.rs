@@ -21,3 +21,37 @@ fn test_list_virtual_host_channels() {
     let result1 = rc.list_channels_in(vh_name);
     assert!(result1.is_ok(), "list_channels_in returned {:?}", result1);
 }
+
+#[test]
+fn test_list_channels_with_unacked_above() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.list_channels_with_unacked_above(0);
+    assert!(
+        result1.is_ok(),
+        "list_channels_with_unacked_above returned {:?}",
+        result1
+    );
+    assert!(result1
+        .unwrap()
+        .iter()
+        .all(|ch| ch.messages_unacknowledged > 0));
+
+    let result2 = rc.list_channels_with_unacked_above(u32::MAX);
+    assert!(result2.is_ok());
+    assert!(result2.unwrap().is_empty());
+}
+
+#[test]
+fn test_close_channel_that_does_not_exist() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let name = "127.0.0.1:1 -> 127.0.0.1:5672 (1)";
+    let result1 = rc.get_channel_info(name);
+    assert!(result1.is_err());
+
+    let result2 = rc.close_channel(name, Some("closed by a test"));
+    assert!(result2.is_ok(), "close_channel returned {:?}", result2);
+}
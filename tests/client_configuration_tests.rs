@@ -0,0 +1,232 @@
+use rabbitmq_http_client::blocking::{Client, Error};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+mod common;
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
+
+#[test]
+fn test_client_with_connection_pool_settings() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_pool_idle_timeout(Duration::from_secs(30))
+        .with_pool_max_idle_per_host(2);
+
+    let result1 = rc.list_nodes();
+    assert!(result1.is_ok(), "list_nodes returned {:?}", result1);
+
+    let result2 = rc.list_vhosts();
+    assert!(result2.is_ok(), "list_vhosts returned {:?}", result2);
+}
+
+#[test]
+fn test_client_with_http1_only() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_http1_only();
+
+    let result = rc.list_nodes();
+    assert!(result.is_ok(), "list_nodes returned {:?}", result);
+}
+
+#[test]
+fn test_client_for_cluster() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    // there is no gateway in front of the test node, so a prefixed path does not exist
+    let prefixed = rc.for_cluster("cluster-a");
+    let result1 = prefixed.list_nodes();
+    assert!(
+        matches!(result1, Err(Error::ClientErrorResponse(404, _))),
+        "list_nodes returned {:?}",
+        result1
+    );
+
+    // the original client is not affected
+    let result2 = rc.list_nodes();
+    assert!(result2.is_ok(), "list_nodes returned {:?}", result2);
+}
+
+#[test]
+fn test_clients_for_cluster_share_http_client() {
+    let server = serve_fixtures(vec![
+        ("/cluster-a/api/vhosts", "[]".to_owned()),
+        ("/cluster-b/api/vhosts", "[]".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let cluster_a = rc.for_cluster("cluster-a");
+    let cluster_b = rc.for_cluster("cluster-b");
+
+    let result1 = cluster_a.list_vhosts();
+    assert!(result1.is_ok(), "list_vhosts returned {:?}", result1);
+    let result2 = cluster_b.list_vhosts();
+    assert!(result2.is_ok(), "list_vhosts returned {:?}", result2);
+
+    // the second request reuses the pooled connection of the first one
+    assert_eq!(server.connections(), 1);
+}
+
+#[test]
+fn test_client_with_request_inspector() {
+    let endpoint = endpoint();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen2 = Arc::clone(&seen);
+    let rc = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_request_inspector(move |req| {
+            assert!(!req.headers().contains_key("authorization"));
+            seen2
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", req.method(), req.url().path()));
+        });
+
+    let result = rc.list_vhosts();
+    assert!(result.is_ok(), "list_vhosts returned {:?}", result);
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(*seen, vec!["GET /api/vhosts"]);
+}
+
+#[test]
+fn test_verify_connection() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let result1 = rc.verify_connection();
+    assert!(result1.is_ok(), "verify_connection returned {:?}", result1);
+
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, "not-the-password");
+    let result2 = rc.verify_connection();
+    assert!(
+        matches!(result2, Err(Error::AuthenticationFailed(_))),
+        "verify_connection returned {:?}",
+        result2
+    );
+
+    // nothing listens on this port
+    let unreachable = "http://localhost:1/api";
+    let rc = Client::new(unreachable).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let result3 = rc.verify_connection();
+    assert!(
+        matches!(result3, Err(Error::RequestError(_))),
+        "verify_connection returned {:?}",
+        result3
+    );
+}
+
+#[test]
+fn test_client_without_preemptive_auth() {
+    let endpoint = endpoint();
+    let seen = Arc::new(Mutex::new(0));
+    let seen2 = Arc::clone(&seen);
+    let rc = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_preemptive_auth(false)
+        .with_request_inspector(move |_| *seen2.lock().unwrap() += 1);
+
+    let result = rc.list_vhosts();
+    assert!(result.is_ok(), "list_vhosts returned {:?}", result);
+
+    // the first request is answered with a challenge
+    assert_eq!(*seen.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_error_request_context() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.get_queue_info("/", "rust.tests.no_such_queue");
+    assert!(
+        matches!(result, Err(Error::ClientErrorResponse(404, _))),
+        "get_queue_info returned {:?}",
+        result
+    );
+    let err = result.unwrap_err();
+    let context = err.request_context();
+    assert!(context.is_some());
+    assert_eq!(
+        context.unwrap().to_string(),
+        "GET /api/queues/%2F/rust%2Etests%2Eno%5Fsuch%5Fqueue"
+    );
+}
+
+#[test]
+fn test_error_display_includes_request_context() {
+    // the fixture server responds with a 404 to unknown paths
+    let server = serve_fixtures(vec![]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let err = rc.get_queue_info("/", "orders").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "API responded with a client error: status code of 404 (GET /api/queues/%2F/orders)"
+    );
+}
+
+#[test]
+fn test_client_with_timeouts() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_timeout(Duration::from_secs(10))
+        .with_connect_timeout(Duration::from_secs(3));
+
+    let result1 = rc.list_nodes();
+    assert!(result1.is_ok(), "list_nodes returned {:?}", result1);
+
+    // no response can arrive in time
+    let rc = rc.with_timeout(Duration::from_nanos(1));
+    let result2 = rc.list_nodes();
+    assert!(
+        matches!(result2, Err(Error::RequestError(_))),
+        "list_nodes returned {:?}",
+        result2
+    );
+}
+
+#[test]
+fn test_client_with_retries() {
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let attempts2 = Arc::clone(&attempts);
+    // nothing listens on this port
+    let unreachable = "http://localhost:1/api";
+    let rc = Client::new(unreachable)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_retries(2, Duration::from_millis(10))
+        .with_request_inspector(move |req| attempts2.lock().unwrap().push(req.method().clone()));
+
+    let result1 = rc.list_vhosts();
+    assert!(
+        matches!(result1, Err(Error::RequestError(_))),
+        "list_vhosts returned {:?}",
+        result1
+    );
+    assert_eq!(attempts.lock().unwrap().len(), 3);
+
+    // requests that modify state are not retried
+    attempts.lock().unwrap().clear();
+    let result2 = rc.delete_vhost("rust.tests.retries");
+    assert!(result2.is_err());
+    assert_eq!(*attempts.lock().unwrap(), vec![reqwest::Method::DELETE]);
+}
+
+#[test]
+fn test_client_with_accept_header() {
+    let endpoint = endpoint();
+    let result1 = Client::new(&endpoint)
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_accept_header("application/json;charset=utf-8");
+    assert!(result1.is_ok());
+
+    let result2 = result1.unwrap().list_nodes();
+    assert!(result2.is_ok(), "list_nodes returned {:?}", result2);
+
+    let result3 = Client::new(&endpoint).with_accept_header("application/json\r\n");
+    assert!(matches!(result3, Err(Error::InvalidHeaderValue(_))));
+}
@@ -1,4 +1,5 @@
-use rabbitmq_http_client::blocking::Client;
+use rabbitmq_http_client::{blocking::Client, responses::ClusterTags};
+use serde_json::json;
 
 mod common;
 use crate::common::{endpoint, PASSWORD, USERNAME};
@@ -34,3 +35,97 @@ fn test_set_cluster_name() {
 
     let _ = rc.set_cluster_name(&meta1.name);
 }
+
+#[test]
+fn test_get_sample_retention_policies() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.get_sample_retention_policies();
+    assert!(
+        result.is_ok(),
+        "get_sample_retention_policies returned {:?}",
+        result
+    );
+    let policies = result.unwrap();
+    assert!(!policies.global.is_empty());
+    assert!(!policies.basic.is_empty());
+}
+
+#[test]
+fn test_get_overview() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.get_overview();
+    assert!(result.is_ok(), "get_overview returned {:?}", result);
+    let overview = result.unwrap();
+    assert!(overview.cluster_name.starts_with("rabbit"));
+    assert!(overview.object_totals.exchanges > 0);
+}
+
+#[test]
+fn test_global_parameters() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let name = "rust.tests.global_parameter";
+
+    let result1 = rc.set_global_parameter(name, json!({"owner": "rust.tests"}));
+    assert!(
+        result1.is_ok(),
+        "set_global_parameter returned {:?}",
+        result1
+    );
+
+    let result2 = rc.get_global_parameter(name);
+    assert!(
+        result2.is_ok(),
+        "get_global_parameter returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap().value, json!({"owner": "rust.tests"}));
+
+    let result3 = rc.list_global_parameters();
+    assert!(
+        result3.is_ok(),
+        "list_global_parameters returned {:?}",
+        result3
+    );
+    assert!(result3.unwrap().iter().any(|p| p.name == "cluster_name"));
+
+    let result4 = rc.clear_global_parameter(name);
+    assert!(
+        result4.is_ok(),
+        "clear_global_parameter returned {:?}",
+        result4
+    );
+    assert!(rc.get_global_parameter(name).is_err());
+}
+
+#[test]
+fn test_cluster_tags() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.get_cluster_tags();
+    assert!(result1.is_ok(), "get_cluster_tags returned {:?}", result1);
+    let original = result1.unwrap();
+
+    let mut tags = ClusterTags::new();
+    tags.insert("region".to_owned(), json!("ca-central-1"));
+    let result2 = rc.set_cluster_tags(tags);
+    assert!(result2.is_ok(), "set_cluster_tags returned {:?}", result2);
+
+    let result3 = rc.set_cluster_metadata("owner", "rust.tests");
+    assert!(
+        result3.is_ok(),
+        "set_cluster_metadata returned {:?}",
+        result3
+    );
+
+    let tags = rc.get_cluster_tags().unwrap();
+    assert_eq!(tags.get("region"), Some(&json!("ca-central-1")));
+    assert_eq!(tags.get("owner"), Some(&json!("rust.tests")));
+
+    let _ = rc.set_cluster_tags(original);
+}
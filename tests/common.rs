@@ -1,4 +1,12 @@
+use std::collections::HashMap;
 use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+use std::thread;
 use std::time::Duration;
 
 pub const ENDPOINT: &str = "http://localhost:15672/api";
@@ -19,3 +27,91 @@ pub fn await_queue_metric_emission() {
     let delay = env::var("TEST_STATS_DELAY").unwrap_or("500".to_owned());
     await_metric_emission(delay.parse::<u64>().unwrap());
 }
+
+/// A local HTTP server that serves canned JSON responses, see [`serve_fixtures`].
+#[allow(dead_code)]
+pub struct FixtureServer {
+    /// The API endpoint to pass to the client
+    pub endpoint: String,
+    connections: Arc<AtomicUsize>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+#[allow(dead_code)]
+impl FixtureServer {
+    /// The number of connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// The method and path of every request served so far, e.g. `PUT /api/vhosts/%2F`.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Serves canned JSON responses keyed by request path, whatever the method
+/// (the query string is ignored). Responds with a 404 to requests for other paths.
+/// Connections are kept alive.
+#[allow(dead_code)]
+pub fn serve_fixtures(routes: Vec<(&'static str, String)>) -> FixtureServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let routes: Arc<HashMap<&'static str, String>> = Arc::new(routes.into_iter().collect());
+    let connections = Arc::new(AtomicUsize::new(0));
+    let connections2 = Arc::clone(&connections);
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let requests2 = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            connections2.fetch_add(1, Ordering::SeqCst);
+            let routes = Arc::clone(&routes);
+            let requests = Arc::clone(&requests2);
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut path = String::new();
+                let mut content_length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap_or(0) > 0 {
+                    if path.is_empty() {
+                        // e.g. "GET /api/queues?columns=name HTTP/1.1"
+                        let mut parts = line.split(' ');
+                        let method = parts.next().unwrap_or_default();
+                        let target = parts.next().unwrap_or_default();
+                        path = target.split('?').next().unwrap_or_default().to_owned();
+                        requests
+                            .lock()
+                            .unwrap()
+                            .push(format!("{} {}", method, path));
+                    } else if let Some(value) = line.to_lowercase().strip_prefix("content-length:")
+                    {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    } else if line == "\r\n" {
+                        let mut request_body = vec![0; content_length];
+                        let _ = reader.read_exact(&mut request_body);
+                        content_length = 0;
+                        let (status, body) = match routes.get(path.as_str()) {
+                            Some(body) => ("200 OK", body.as_str()),
+                            None => ("404 Not Found", "{}"),
+                        };
+                        let response = format!(
+                            "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                            status,
+                            body.len(),
+                            body
+                        );
+                        let _ = stream.write_all(response.as_bytes());
+                        path.clear();
+                    }
+                    line.clear();
+                }
+            });
+        }
+    });
+    FixtureServer {
+        endpoint: format!("http://127.0.0.1:{}/api", port),
+        connections,
+        requests,
+    }
+}
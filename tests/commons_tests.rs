@@ -0,0 +1,211 @@
+use rabbitmq_http_client::{
+    commons::{
+        topic_pattern_matches, BindingDestinationType, ByteCapacity, ByteCapacityParseError,
+        ExchangeType, FeatureFlagStability, FeatureFlagState, Overflow, QueueType,
+    },
+    requests::{
+        QueueArgumentsBuilder, QueueParams, RuntimeParameterDefinition, ShovelEndpoint,
+        ShovelParams,
+    },
+};
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn test_byte_capacity_parsing() {
+    assert_eq!(
+        "2GB".parse::<ByteCapacity>().unwrap().bytes(),
+        2 * 1024 * 1024 * 1024
+    );
+    assert_eq!(
+        "500MB".parse::<ByteCapacity>().unwrap().bytes(),
+        500 * 1024 * 1024
+    );
+    assert_eq!(
+        "1TB".parse::<ByteCapacity>().unwrap().bytes(),
+        1024 * 1024 * 1024 * 1024
+    );
+    assert_eq!("16 KiB".parse::<ByteCapacity>().unwrap().bytes(), 16 * 1024);
+    assert_eq!("4096".parse::<ByteCapacity>().unwrap().bytes(), 4096);
+    assert_eq!("1gb".parse::<ByteCapacity>().unwrap().bytes(), 1 << 30);
+}
+
+#[test]
+fn test_byte_capacity_parsing_errors() {
+    assert_eq!(
+        "".parse::<ByteCapacity>(),
+        Err(ByteCapacityParseError::Empty)
+    );
+    assert_eq!(
+        "2XB".parse::<ByteCapacity>(),
+        Err(ByteCapacityParseError::UnknownUnit("XB".to_owned()))
+    );
+    assert!(matches!(
+        "GB".parse::<ByteCapacity>(),
+        Err(ByteCapacityParseError::InvalidNumber(_))
+    ));
+    assert_eq!(
+        "99999999999TB".parse::<ByteCapacity>(),
+        Err(ByteCapacityParseError::Overflow)
+    );
+}
+
+#[test]
+fn test_byte_capacity_display() {
+    assert_eq!("2GB".parse::<ByteCapacity>().unwrap().to_string(), "2GB");
+    assert_eq!(ByteCapacity::from_bytes(1536).to_string(), "1536B");
+    assert_eq!(ByteCapacity::from_bytes(3 * 1024).to_string(), "3KB");
+    assert_eq!(ByteCapacity::from_bytes(0).to_string(), "0B");
+}
+
+#[test]
+fn test_byte_capacity_serde() {
+    let capacity: ByteCapacity = "500MB".parse().unwrap();
+    assert_eq!(serde_json::to_string(&capacity).unwrap(), "524288000");
+    assert_eq!(
+        serde_json::from_str::<ByteCapacity>("\"500MB\"").unwrap(),
+        capacity
+    );
+    assert_eq!(
+        serde_json::from_str::<ByteCapacity>("524288000").unwrap(),
+        capacity
+    );
+}
+
+#[test]
+fn test_queue_params_with_max_length_bytes() {
+    let params = QueueParams::new_stream("rust.tests.stream.max_length_bytes", None)
+        .with_max_length_bytes("2GB".parse().unwrap());
+    let args = params.arguments.unwrap();
+    assert_eq!(
+        args.get("x-max-length-bytes").unwrap().as_u64().unwrap(),
+        2 * 1024 * 1024 * 1024
+    );
+    assert!(args.contains_key("x-queue-type"));
+}
+
+#[test]
+fn test_queue_arguments_builder() {
+    let args = QueueArgumentsBuilder::new()
+        .max_length(1000)
+        .max_length_bytes(1 << 20)
+        .message_ttl(Duration::from_secs(30))
+        .dead_letter_exchange("dlx")
+        .overflow(Overflow::RejectPublishDlx)
+        .argument("x-single-active-consumer", json!(true))
+        .build();
+    let params = QueueParams::new_quorum_queue("rust.tests.qq.arguments_builder", args);
+
+    let args = params.arguments.unwrap();
+    assert_eq!(args.get("x-max-length"), Some(&json!(1000)));
+    assert_eq!(args.get("x-max-length-bytes"), Some(&json!(1 << 20)));
+    assert_eq!(args.get("x-message-ttl"), Some(&json!(30_000)));
+    assert_eq!(args.get("x-dead-letter-exchange"), Some(&json!("dlx")));
+    assert_eq!(args.get("x-overflow"), Some(&json!("reject-publish-dlx")));
+    assert_eq!(args.get("x-single-active-consumer"), Some(&json!(true)));
+    assert_eq!(args.get("x-queue-type"), Some(&json!("quorum")));
+}
+
+#[test]
+fn test_shovel_params_to_runtime_parameter() {
+    let mut params = ShovelParams::queue_to_queue(
+        "/",
+        "orders.to.archive",
+        "amqp://localhost",
+        "orders",
+        "amqp://archive",
+        "unused",
+    );
+    params.destination = ShovelEndpoint::Exchange {
+        name: "archive",
+        routing_key: Some("orders"),
+    };
+
+    let rp = RuntimeParameterDefinition::from(&params);
+    assert_eq!(rp.component, "shovel");
+    assert_eq!(rp.value.get("src-queue"), Some(&json!("orders")));
+    assert_eq!(rp.value.get("dest-exchange"), Some(&json!("archive")));
+    assert_eq!(rp.value.get("dest-exchange-key"), Some(&json!("orders")));
+    assert!(!rp.value.contains_key("dest-queue"));
+    assert_eq!(rp.value.get("ack-mode"), Some(&json!("on-confirm")));
+    assert_eq!(rp.value.get("src-delete-after"), Some(&json!("never")));
+}
+
+#[test]
+fn test_topic_pattern_matching() {
+    assert!(topic_pattern_matches("orders.created", "orders.created"));
+    assert!(!topic_pattern_matches("orders.created", "orders.updated"));
+
+    assert!(topic_pattern_matches("orders.*", "orders.created"));
+    assert!(!topic_pattern_matches("orders.*", "orders"));
+    assert!(!topic_pattern_matches("orders.*", "orders.created.eu"));
+
+    assert!(topic_pattern_matches("orders.#", "orders"));
+    assert!(topic_pattern_matches("orders.#", "orders.created.eu"));
+    assert!(topic_pattern_matches("#", "anything.at.all"));
+    assert!(topic_pattern_matches("#.eu", "orders.created.eu"));
+    assert!(topic_pattern_matches("*.created.#", "orders.created"));
+    assert!(!topic_pattern_matches("*.created.#", "created"));
+}
+
+#[test]
+fn test_binding_destination_type_display() {
+    assert_eq!(BindingDestinationType::Queue.to_string(), "queue");
+    assert_eq!(BindingDestinationType::Exchange.to_string(), "exchange");
+}
+
+#[test]
+fn test_exchange_type_serde_round_trip() {
+    for (json, expected) in [
+        ("\"topic\"", ExchangeType::Topic),
+        ("\"x-consistent-hash\"", ExchangeType::ConsistentHashing),
+        (
+            "\"x-delayed-message\"",
+            ExchangeType::Plugin("x-delayed-message".to_owned()),
+        ),
+    ] {
+        let typ: ExchangeType = serde_json::from_str(json).unwrap();
+        assert_eq!(typ, expected);
+        assert_eq!(serde_json::to_string(&typ).unwrap(), json);
+    }
+}
+
+#[test]
+fn test_queue_type_deserialization() {
+    let typ: QueueType = serde_json::from_str("\"quorum\"").unwrap();
+    assert_eq!(typ, QueueType::Quorum);
+    assert_eq!(serde_json::to_string(&typ).unwrap(), "\"quorum\"");
+}
+
+#[test]
+fn test_queue_type_serde_round_trip_of_unknown_type() {
+    let json = "\"rabbit_mqtt_qos0_queue\"";
+    let typ: QueueType = serde_json::from_str(json).unwrap();
+    assert_eq!(typ, QueueType::Other("rabbit_mqtt_qos0_queue".to_owned()));
+    assert_eq!(serde_json::to_string(&typ).unwrap(), json);
+}
+
+#[test]
+fn test_feature_flag_stability_serde_round_trip() {
+    for (json, expected) in [
+        ("\"stable\"", FeatureFlagStability::Stable),
+        ("\"experimental\"", FeatureFlagStability::Experimental),
+        ("\"deprecated\"", FeatureFlagStability::Deprecated),
+        (
+            "\"provisional\"",
+            FeatureFlagStability::Other("provisional".to_owned()),
+        ),
+    ] {
+        let stability: FeatureFlagStability = serde_json::from_str(json).unwrap();
+        assert_eq!(stability, expected);
+        assert_eq!(serde_json::to_string(&stability).unwrap(), json);
+    }
+}
+
+#[test]
+fn test_feature_flag_state_deserialization() {
+    let state: FeatureFlagState = serde_json::from_str("\"unavailable\"").unwrap();
+    assert_eq!(state, FeatureFlagState::Unavailable);
+    let state: FeatureFlagState = serde_json::from_str("\"state_changing\"").unwrap();
+    assert_eq!(state, FeatureFlagState::StateChanging);
+}
@@ -1,7 +1,9 @@
 use rabbitmq_http_client::blocking::Client;
+use serde_json::{json, Value};
+use std::time::Duration;
 
 mod common;
-use crate::common::{endpoint, PASSWORD, USERNAME};
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_connections() {
@@ -12,6 +14,40 @@ fn test_list_connections() {
     assert!(result1.is_ok(), "list_connections returned {:?}", result1);
 }
 
+#[test]
+fn test_list_connections_by_product() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.list_connections_by_product("a product that does not exist");
+    assert!(
+        result1.is_ok(),
+        "list_connections_by_product returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().is_empty());
+}
+
+#[test]
+fn test_list_connections_by_product_filters_by_product() {
+    let java: Value = serde_json::from_str(include_str!("fixtures/connection.json")).unwrap();
+    let mut ruby = java.clone();
+    ruby["name"] = json!("127.0.0.1:54211 -> 127.0.0.1:5672");
+    ruby["client_properties"]["product"] = json!("Bunny");
+    let server = serve_fixtures(vec![("/api/connections", json!([java, ruby]).to_string())]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.list_connections_by_product("bunny");
+    assert!(
+        result.is_ok(),
+        "list_connections_by_product returned {:?}",
+        result
+    );
+    let connections = result.unwrap();
+    assert_eq!(connections.len(), 1);
+    assert_eq!(connections[0].name, "127.0.0.1:54211 -> 127.0.0.1:5672");
+}
+
 #[test]
 fn test_list_user_connections() {
     let endpoint = endpoint();
@@ -38,3 +74,33 @@ fn test_list_virtual_host_connections() {
         result1
     );
 }
+
+#[test]
+fn test_close_connections_older_than() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    // no test suite connection is going to be a year old
+    let result1 = rc.close_connections_older_than(Duration::from_secs(365 * 24 * 3600), None);
+    assert!(
+        result1.is_ok(),
+        "close_connections_older_than returned {:?}",
+        result1
+    );
+    assert_eq!(0, result1.unwrap());
+}
+
+#[test]
+fn test_close_user_connections() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    // the user does not exist, so there are no connections to close
+    let result1 = rc.close_user_connections("rust.tests.no_such_user", Some("revoked"));
+    assert!(
+        result1.is_ok(),
+        "close_user_connections returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap(), 0);
+}
@@ -1,7 +1,8 @@
 use rabbitmq_http_client::{blocking::Client, requests::VirtualHostParams};
+use serde_json::{json, Value};
 
 mod common;
-use crate::common::{endpoint, PASSWORD, USERNAME};
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_consumers() {
@@ -24,3 +25,68 @@ fn test_list_vhost_consumers() {
     let result2 = rc.list_consumers_in(vh_params.name);
     assert!(result2.is_ok(), "list_consumers_in returned {:?}", result2);
 }
+
+#[test]
+fn test_list_unbounded_prefetch_consumers_in() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_unbounded_prefetch_consumers_in");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.list_unbounded_prefetch_consumers_in(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "list_unbounded_prefetch_consumers_in returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().is_empty());
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[test]
+fn test_list_unbounded_prefetch_consumers_in_filters_by_prefetch() {
+    let queue: Value =
+        serde_json::from_str(include_str!("fixtures/queue_info_with_consumers.json")).unwrap();
+    let bounded = queue["consumer_details"][0].clone();
+    let mut unbounded = bounded.clone();
+    unbounded["consumer_tag"] = json!("amq.ctag-unbounded");
+    unbounded["prefetch_count"] = json!(0);
+    let server = serve_fixtures(vec![(
+        "/api/consumers/%2F",
+        json!([bounded, unbounded]).to_string(),
+    )]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.list_unbounded_prefetch_consumers_in("/");
+    assert!(
+        result.is_ok(),
+        "list_unbounded_prefetch_consumers_in returned {:?}",
+        result
+    );
+    let consumers = result.unwrap();
+    assert_eq!(consumers.len(), 1);
+    assert_eq!(consumers[0].consumer_tag, "amq.ctag-unbounded");
+}
+
+#[test]
+fn test_list_consumers_in_vhosts_with_slashes() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("a/b");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let result2 = rc.list_consumers_in("/");
+    assert!(result2.is_ok(), "list_consumers_in returned {:?}", result2);
+
+    let result3 = rc.list_consumers_in(vh_params.name);
+    assert!(result3.is_ok(), "list_consumers_in returned {:?}", result3);
+    assert!(result3.unwrap().is_empty());
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
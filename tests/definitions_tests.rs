@@ -1,4 +1,4 @@
-use rabbitmq_http_client::blocking::Client;
+use rabbitmq_http_client::{blocking::Client, requests::QueueParams, responses::Definitions};
 
 mod common;
 use crate::common::{endpoint, PASSWORD, USERNAME};
@@ -13,6 +13,25 @@ fn test_export_definitions() {
     assert!(result.is_ok(), "export_definitions returned {:?}", result);
 }
 
+#[test]
+fn test_export_definitions_as_data() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let result = rc.export_definitions_as_data();
+
+    assert!(
+        result.is_ok(),
+        "export_definitions_as_data returned {:?}",
+        result
+    );
+    let defs = result.unwrap();
+    assert!(defs.vhosts.iter().any(|vh| vh.name == "/"));
+    assert!(defs.users.iter().any(|u| u.name == USERNAME));
+
+    let json = defs.to_json();
+    assert!(json.get("vhosts").unwrap().is_array());
+}
+
 #[test]
 fn test_import_definitions() {
     let endpoint = endpoint();
@@ -37,3 +56,87 @@ fn test_import_definitions() {
         result1
     );
 }
+
+#[test]
+fn test_export_definition_sections() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let q = "rust.tests.cq.export_definition_sections";
+    let _ = rc.delete_queue("/", q);
+    rc.declare_queue("/", &QueueParams::new_durable_classic_queue(q, None))
+        .unwrap();
+
+    let result1 = rc.export_queues();
+    assert!(result1.is_ok(), "export_queues returned {:?}", result1);
+    assert!(result1.unwrap().iter().any(|it| it.name == q));
+
+    let result2 = rc.export_exchanges();
+    assert!(result2.is_ok(), "export_exchanges returned {:?}", result2);
+
+    let result3 = rc.export_bindings();
+    assert!(result3.is_ok(), "export_bindings returned {:?}", result3);
+
+    let result4 = rc.export_policies();
+    assert!(result4.is_ok(), "export_policies returned {:?}", result4);
+
+    let result5 = rc.export_runtime_parameters();
+    assert!(
+        result5.is_ok(),
+        "export_runtime_parameters returned {:?}",
+        result5
+    );
+
+    let _ = rc.delete_queue("/", q);
+}
+
+#[test]
+fn test_plan_import() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let q = "rust.tests.cq.plan_import";
+    let _ = rc.delete_queue("/", q);
+
+    let defs: Definitions = serde_json::from_value(json!({  "queues": [
+      {
+        "auto_delete": false,
+        "durable": true,
+        "name": q,
+        "vhost": "/",
+        "arguments": {}
+      }
+    ]}))
+    .unwrap();
+
+    let result1 = rc.plan_import(&defs);
+    assert!(result1.is_ok(), "plan_import returned {:?}", result1);
+    let plan1 = result1.unwrap();
+    assert_eq!(plan1.queues.to_create.len(), 1);
+
+    // planning must not apply anything
+    assert!(rc.get_queue_info("/", q).is_err());
+
+    rc.declare_queue("/", &QueueParams::new_durable_classic_queue(q, None))
+        .unwrap();
+    let plan2 = rc.plan_import(&defs).unwrap();
+    assert!(plan2.queues.to_create.is_empty());
+
+    let _ = rc.delete_queue("/", q);
+}
+
+#[test]
+fn test_export_definitions_to_writer() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = rc.export_definitions_to_writer(&mut buf);
+    assert!(
+        result.is_ok(),
+        "export_definitions_to_writer returned {:?}",
+        result
+    );
+    assert_eq!(result.unwrap(), buf.len() as u64);
+
+    let defs: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert!(defs.get("vhosts").is_some());
+}
@@ -0,0 +1,30 @@
+use rabbitmq_http_client::blocking::Client;
+
+mod common;
+use crate::common::{endpoint, PASSWORD, USERNAME};
+
+#[test]
+fn test_list_deprecated_features() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.list_deprecated_features();
+    assert!(
+        result.is_ok(),
+        "list_deprecated_features returned {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_list_deprecated_features_in_use() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.list_deprecated_features_in_use();
+    assert!(
+        result.is_ok(),
+        "list_deprecated_features_in_use returned {:?}",
+        result
+    );
+}
@@ -0,0 +1,857 @@
+use rabbitmq_http_client::{
+    blocking::Error,
+    commons::{
+        AckMode, AlarmResource, BindingDestinationType, DeprecationPhase, ExchangeType,
+        FeatureFlagStability, FeatureFlagState, PolicyTarget, QueueType,
+    },
+    requests::QueueParams,
+    responses::{
+        self, AlivenessCheckDetails, BindingInfo, CertificateExpirationCheckDetails, Channel,
+        ClusterAlarmCheckDetails, ClusterNode, Connection, Consumer, Definitions,
+        DeprecatedFeature, ExchangeInfo, FeatureFlag, HealthCheckFailureDetails, ImportPlan,
+        Overview, Policy, PortListenerCheckDetails, QueueInfo, QuorumCriticalityCheckDetails,
+        ReplicaRole, RuntimeParameter, SampleRetentionPolicies, ShovelStatus, UserLimits,
+        VirtualHost, VirtualHostLimits, VirtualHostsCheckDetails,
+    },
+};
+use serde_json::{json, Map, Value};
+
+#[test]
+fn test_deserialize_stream_queue_info() {
+    let json = include_str!("fixtures/queue_info_stream.json");
+    let result = serde_json::from_str::<QueueInfo>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a stream: {:?}",
+        result
+    );
+
+    let q = result.unwrap();
+    assert_eq!(q.name, "stream.1");
+    assert_eq!(q.queue_type, QueueType::Stream);
+    assert_eq!(q.state, "running");
+    assert_eq!(q.leader.as_deref(), Some("rabbit@sunnyvale"));
+    assert_eq!(q.member_count(), 1);
+    assert_eq!(q.online_member_count(), 1);
+    assert_eq!(q.consumer_count, 2);
+    assert_eq!(q.message_count, 3812);
+    assert_eq!(q.committed_offset, Some(3811));
+    assert_eq!(q.segments, Some(1));
+    assert!(!q.exclusive);
+    assert_eq!(q.consumer_utilisation, 0.0);
+}
+
+#[test]
+fn test_deserialize_queue_info_list_with_streams() {
+    let json = include_str!("fixtures/queue_info_list_with_streams.json");
+    let result = serde_json::from_str::<Vec<QueueInfo>>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a mix of classic queues and streams: {:?}",
+        result
+    );
+
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 2);
+
+    let cq = &vec[0];
+    assert_eq!(cq.queue_type, QueueType::Classic);
+    assert_eq!(cq.consumer_utilisation, 0.0);
+    assert!(cq.committed_offset.is_none());
+
+    let stream = &vec[1];
+    assert_eq!(stream.queue_type, QueueType::Stream);
+    assert_eq!(stream.state, "minority");
+    assert_eq!(stream.member_count(), 2);
+    assert_eq!(stream.online_member_count(), 1);
+    assert_eq!(stream.node, "");
+    assert_eq!(stream.committed_offset, Some(-1));
+}
+
+#[test]
+fn test_deserialize_quorum_queue_info() {
+    let json = include_str!("fixtures/queue_info_quorum.json");
+    let result = serde_json::from_str::<QueueInfo>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a quorum queue: {:?}",
+        result
+    );
+
+    let q = result.unwrap();
+    assert_eq!(q.name, "qq.1");
+    assert_eq!(q.queue_type, QueueType::Quorum);
+    assert!(q.durable);
+    assert_eq!(q.policy.as_deref(), Some("qq.limits"));
+    assert_eq!(q.member_count(), 3);
+    assert_eq!(q.online_member_count(), 2);
+    assert_eq!(q.message_count, 3);
+    assert_eq!(q.message_bytes, 300);
+    assert!(q.committed_offset.is_none());
+
+    let replicas = q.replica_statuses();
+    assert_eq!(replicas.len(), 3);
+    assert_eq!(replicas[0].node, "rabbit@sunnyvale");
+    assert_eq!(replicas[0].role, ReplicaRole::Leader);
+    assert!(replicas[0].online);
+    assert_eq!(replicas[2].node, "rabbit@mountainview");
+    assert_eq!(replicas[2].role, ReplicaRole::Follower);
+    assert!(!replicas[2].online);
+}
+
+#[test]
+fn test_queue_params_from_queue_info() {
+    let json = include_str!("fixtures/queue_info_stream.json");
+    let q = serde_json::from_str::<QueueInfo>(json).unwrap();
+
+    let params = QueueParams::from(&q);
+    assert_eq!(params.name, "stream.1");
+    assert_eq!(params.queue_type, QueueType::Stream);
+    assert!(params.durable);
+    assert!(!params.auto_delete);
+    assert!(!params.exclusive);
+
+    let args = params.arguments.unwrap();
+    assert_eq!(args.get("x-max-length-bytes"), Some(&json!(10000000)));
+    assert_eq!(args.get("x-queue-type"), Some(&json!("stream")));
+}
+
+#[test]
+fn test_deserialize_connection() {
+    let json = include_str!("fixtures/connection.json");
+    let result = serde_json::from_str::<Connection>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a connection: {:?}",
+        result
+    );
+
+    let c = result.unwrap();
+    assert_eq!(c.name, "127.0.0.1:54210 -> 127.0.0.1:5672");
+    assert_eq!(c.username, "guest");
+    assert_eq!(c.protocol, "AMQP 0-9-1");
+    assert_eq!(c.connected_at, 1689854400000);
+    assert_eq!(c.server_port, 5672);
+    assert_eq!(c.client_port, 54210);
+    assert_eq!(c.channel_max, 2047);
+    assert_eq!(c.channel_count, 1);
+    assert_eq!(c.client_properties.connection_name, "orders-service");
+    assert_eq!(c.client_properties.product, "RabbitMQ");
+    assert!(c.client_properties.capabilities.unwrap().publisher_confirms);
+    assert_eq!(c.heartbeat, Some(60));
+    assert_eq!(c.frame_max, Some(131072));
+}
+
+#[test]
+fn test_deserialize_cluster_node() {
+    let json = include_str!("fixtures/cluster_node.json");
+    let result = serde_json::from_str::<ClusterNode>(json);
+    assert!(result.is_ok(), "failed to deserialize a node: {:?}", result);
+
+    let n = result.unwrap();
+    assert_eq!(n.name, "rabbit@sunnyvale");
+    // os_pid is reported as a string
+    assert_eq!(n.os_pid, 39581);
+    assert_eq!(n.processors, 10);
+    assert_eq!(n.memory_high_watermark, 13435805286);
+    assert!(!n.has_memory_alarm_in_effect);
+    assert!(!n.has_free_disk_space_alarm_in_effect);
+    assert_eq!(n.rates_mode, "basic");
+    assert_eq!(n.gc_num, Some(124533));
+    assert_eq!(n.gc_num_details.unwrap().rate, 42.6);
+    assert_eq!(n.gc_bytes_reclaimed, Some(3911426376));
+    assert!(n.gc_bytes_reclaimed_details.is_none());
+    assert_eq!(n.io_read_count, Some(1));
+    assert_eq!(n.io_write_count, Some(0));
+    assert_eq!(n.context_switches, Some(1423523));
+}
+
+#[test]
+fn test_deserialize_policy() {
+    let json = include_str!("fixtures/policy.json");
+    let result = serde_json::from_str::<Policy>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a policy: {:?}",
+        result
+    );
+
+    let p = result.unwrap();
+    assert_eq!(p.name, "qq.limits");
+    assert_eq!(p.pattern, "^qq\\.");
+    assert_eq!(p.apply_to, PolicyTarget::QuorumQueues);
+    assert_eq!(p.priority, 7);
+    let defs = p.definition.unwrap();
+    assert_eq!(defs.get("delivery-limit").unwrap().as_u64(), Some(10));
+}
+
+#[test]
+fn test_deserialize_runtime_parameter() {
+    let json = include_str!("fixtures/runtime_parameter.json");
+    let result = serde_json::from_str::<RuntimeParameter>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a runtime parameter: {:?}",
+        result
+    );
+
+    let rp = result.unwrap();
+    assert_eq!(rp.component, "vhost-limits");
+    assert_eq!(rp.vhost, "limited");
+    assert_eq!(
+        rp.value.get("max-connections").unwrap().as_u64(),
+        Some(9988)
+    );
+}
+
+#[test]
+fn test_deserialize_shovel_status() {
+    let json = include_str!("fixtures/shovel_status.json");
+    let result = serde_json::from_str::<ShovelStatus>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a shovel status: {:?}",
+        result
+    );
+
+    let shovel = result.unwrap();
+    assert_eq!(shovel.name, "orders.migration");
+    assert_eq!(shovel.vhost.as_deref(), Some("/"));
+    assert_eq!(shovel.shovel_type, "dynamic");
+    assert_eq!(shovel.state, "running");
+    assert_eq!(shovel.src_queue.as_deref(), Some("orders"));
+    assert!(shovel.src_exchange.is_none());
+    assert!(shovel.reason.is_none());
+}
+
+#[test]
+fn test_deserialize_feature_flags() {
+    let json = include_str!("fixtures/feature_flags.json");
+    let result = serde_json::from_str::<Vec<FeatureFlag>>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize feature flags: {:?}",
+        result
+    );
+
+    let flags = result.unwrap();
+    assert_eq!(flags[0].name, "quorum_queue");
+    assert_eq!(flags[0].state, FeatureFlagState::Enabled);
+    assert_eq!(flags[0].stability, FeatureFlagStability::Required);
+    assert_eq!(flags[1].state, FeatureFlagState::Disabled);
+    assert_eq!(flags[1].stability, FeatureFlagStability::Experimental);
+}
+
+#[test]
+fn test_deserialize_deprecated_features() {
+    let json = include_str!("fixtures/deprecated_features.json");
+    let result = serde_json::from_str::<Vec<DeprecatedFeature>>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize deprecated features: {:?}",
+        result
+    );
+
+    let features = result.unwrap();
+    assert_eq!(features[0].name, "transient_nonexcl_queues");
+    assert_eq!(
+        features[0].deprecation_phase,
+        DeprecationPhase::PermittedByDefault
+    );
+    assert!(features[0].doc_url.starts_with("https://"));
+    assert_eq!(features[1].deprecation_phase, DeprecationPhase::Removed);
+}
+
+#[test]
+fn test_deserialize_bindings() {
+    let json = include_str!("fixtures/bindings.json");
+    let result = serde_json::from_str::<Vec<BindingInfo>>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize bindings: {:?}",
+        result
+    );
+
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec[0].source, "");
+    assert_eq!(vec[1].arguments.get("x-match").unwrap(), "all");
+    assert_eq!(vec[2].destination_type, BindingDestinationType::Exchange);
+}
+
+#[test]
+fn test_deserialize_virtual_host() {
+    let json = include_str!("fixtures/vhost.json");
+    let result = serde_json::from_str::<VirtualHost>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a virtual host: {:?}",
+        result
+    );
+
+    let vh = result.unwrap();
+    assert_eq!(vh.name, "orders");
+    assert_eq!(vh.default_queue_type, Some(QueueType::Quorum));
+    assert_eq!(vh.metadata.default_queue_type, Some(QueueType::Quorum));
+    assert!(!vh.tracing);
+    assert_eq!(vh.messages, Some(20));
+    assert_eq!(vh.messages_ready, Some(12));
+    assert_eq!(vh.messages_unacknowledged, Some(8));
+    let stats = vh.message_stats.unwrap();
+    assert_eq!(stats.publish, Some(150));
+    assert_eq!(stats.publish_details.unwrap().rate, 2.0);
+    assert!(stats.confirm.is_none());
+}
+
+#[test]
+fn test_deserialize_alarm_check_failure_details() {
+    let json = include_str!("fixtures/health_check_alarms_failure.json");
+    let result = serde_json::from_str::<ClusterAlarmCheckDetails>(json);
+    assert!(result.is_ok(), "failed to deserialize alarms: {:?}", result);
+
+    let details = result.unwrap();
+    assert_eq!(details.alarms.len(), 2);
+    assert_eq!(details.alarms[0].node, "rabbit@sunnyvale");
+    assert_eq!(details.alarms[0].resource, AlarmResource::Disk);
+    assert_eq!(details.alarms[1].resource, AlarmResource::Memory);
+}
+
+#[test]
+fn test_deserialize_quorum_criticality_check_failure_details() {
+    let json = include_str!("fixtures/health_check_quorum_critical_failure.json");
+    let result = serde_json::from_str::<QuorumCriticalityCheckDetails>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize quorum criticality details: {:?}",
+        result
+    );
+
+    let details = result.unwrap();
+    assert_eq!(details.queues.len(), 1);
+    assert_eq!(details.queues[0].vhost, "/");
+    assert_eq!(details.queues[0].queue_type, QueueType::Quorum);
+}
+
+#[test]
+fn test_deserialize_virtual_hosts_check_failure_details() {
+    let json = include_str!("fixtures/health_check_virtual_hosts_failure.json");
+    let result = serde_json::from_str::<VirtualHostsCheckDetails>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize virtual host check details: {:?}",
+        result
+    );
+
+    let details = result.unwrap();
+    assert_eq!(details.reason, "Some virtual hosts are down");
+    assert_eq!(details.virtual_hosts, vec!["events", "orders"]);
+}
+
+#[test]
+fn test_deserialize_port_listener_check_failure_details() {
+    let json = include_str!("fixtures/health_check_port_listener_failure.json");
+    let result = serde_json::from_str::<PortListenerCheckDetails>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize port listener check details: {:?}",
+        result
+    );
+
+    let details = result.unwrap();
+    assert_eq!(details.missing, 5671);
+    assert_eq!(details.ports, vec![5672, 15672, 25672]);
+}
+
+#[test]
+fn test_deserialize_certificate_expiration_check_failure_details() {
+    let json = include_str!("fixtures/health_check_certificate_expiration_failure.json");
+    let result = serde_json::from_str::<CertificateExpirationCheckDetails>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize certificate expiration check details: {:?}",
+        result
+    );
+
+    let details = result.unwrap();
+    assert_eq!(details.expired.len(), 1);
+    let cert = &details.expired[0];
+    assert_eq!(cert.node, "rabbit@sunnyside");
+    assert_eq!(cert.interface, "[::]");
+    assert_eq!(cert.port, 5671);
+    assert_eq!(cert.expiry_date, "2026-11-02T12:00:00+00:00");
+}
+
+#[test]
+fn test_deserialize_exchange_info_with_message_stats() {
+    let json = include_str!("fixtures/exchange_info.json");
+    let result = serde_json::from_str::<ExchangeInfo>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize an exchange: {:?}",
+        result
+    );
+
+    let x = result.unwrap();
+    assert_eq!(x.name, "orders");
+    let stats = x.message_stats.unwrap();
+    assert_eq!(stats.publish_in, Some(1500));
+    assert_eq!(stats.publish_out_details.unwrap().rate, 12.0);
+    assert!(stats.publish.is_none());
+}
+
+#[test]
+fn test_deserialize_channel_with_message_stats() {
+    let json = include_str!("fixtures/channel.json");
+    let result = serde_json::from_str::<Channel>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a channel: {:?}",
+        result
+    );
+
+    let ch = result.unwrap();
+    assert_eq!(ch.id, 1);
+    assert_eq!(ch.messages_unacknowledged, 20);
+    let stats = ch.message_stats.unwrap();
+    assert_eq!(stats.ack, Some(540));
+    assert_eq!(stats.redeliver, Some(3));
+}
+
+#[test]
+fn test_deserialize_objects_without_message_stats() {
+    let q = serde_json::from_str::<QueueInfo>(include_str!("fixtures/queue_info_quorum.json"));
+    assert!(q.unwrap().message_stats.is_none());
+
+    let c = serde_json::from_str::<Connection>(include_str!("fixtures/connection.json"));
+    assert!(c.unwrap().message_stats.is_none());
+}
+
+#[test]
+fn test_deserialize_queue_info_with_consumer_details() {
+    let json = include_str!("fixtures/queue_info_with_consumers.json");
+    let result = serde_json::from_str::<QueueInfo>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a queue with consumers: {:?}",
+        result
+    );
+
+    let q = result.unwrap();
+    let consumers = q.consumer_details.unwrap();
+    assert_eq!(consumers.len(), 1);
+    let c = &consumers[0];
+    assert_eq!(c.consumer_tag, "amq.ctag-Hvjz8VfPVUKLlhMqNc6Rsg");
+    assert!(c.ack_required);
+    assert_eq!(c.prefetch_count, 20);
+    assert_eq!(c.channel_details.id, 1);
+    assert_eq!(c.channel_details.username, "guest");
+}
+
+#[test]
+fn test_deserialize_queue_info_list_leniently() {
+    let mut value: Value =
+        serde_json::from_str(include_str!("fixtures/queue_info_list_with_streams.json")).unwrap();
+    // an optional field of an unexpected type
+    value[0]["exclusive_consumer_tag"] = json!(42);
+    // a required field of an unexpected type
+    value[1]["name"] = json!(["stream", 2]);
+
+    let result1 = serde_json::from_value::<Vec<QueueInfo>>(value.clone());
+    assert!(result1.is_err());
+
+    let result2 = responses::deserialize_list_leniently::<QueueInfo>(value);
+    assert!(
+        result2.is_ok(),
+        "failed to deserialize queues leniently: {:?}",
+        result2
+    );
+    let vec = result2.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].name, "cq.1");
+    assert!(vec[0].exclusive_consumer_tag.is_none());
+
+    // a single object cannot be skipped
+    let value = json!({"name": 42});
+    let result3 = responses::deserialize_leniently::<VirtualHost>(value);
+    assert!(result3.is_err());
+
+    // a list none of whose elements can be deserialized is not reported as empty
+    let value = json!([{"name": 42}, {"name": 43}]);
+    let result4 = responses::deserialize_list_leniently::<VirtualHost>(value);
+    assert!(result4.is_err());
+}
+
+#[test]
+fn test_deserialize_exclusive_queue_owner() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/queue_info_with_consumers.json")).unwrap();
+    let obj = value.as_object_mut().unwrap();
+    obj.insert("exclusive".to_owned(), json!(true));
+    obj.insert(
+        "owner_pid_details".to_owned(),
+        json!({
+            "name": "127.0.0.1:54210 -> 127.0.0.1:5672",
+            "peer_host": "127.0.0.1",
+            "peer_port": 54210
+        }),
+    );
+
+    let result = serde_json::from_value::<QueueInfo>(value);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize an exclusive queue: {:?}",
+        result
+    );
+    let q = result.unwrap();
+    assert_eq!(
+        q.owner_connection(),
+        Some("127.0.0.1:54210 -> 127.0.0.1:5672")
+    );
+    assert_eq!(q.owner_pid_details.unwrap().client_port, 54210);
+
+    // queues that are not exclusive have no owner
+    let q: QueueInfo =
+        serde_json::from_str(include_str!("fixtures/queue_info_with_consumers.json")).unwrap();
+    assert!(q.owner_connection().is_none());
+}
+
+#[test]
+fn test_deserialize_exchange_info_with_policy() {
+    let json = include_str!("fixtures/exchange_info_with_policy.json");
+    let result = serde_json::from_str::<ExchangeInfo>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize an exchange with a policy: {:?}",
+        result
+    );
+
+    let x = result.unwrap();
+    assert_eq!(x.policy, Some("ae_policy".to_owned()));
+    assert!(x.message_stats.is_none());
+}
+
+#[test]
+fn test_deserialize_exchange_info_without_policy() {
+    let json = include_str!("fixtures/exchange_info.json");
+    let x = serde_json::from_str::<ExchangeInfo>(json).unwrap();
+    assert!(x.policy.is_none());
+}
+
+#[test]
+fn test_ack_mode_serde() {
+    assert_eq!(
+        serde_json::to_string(&AckMode::OnConfirm).unwrap(),
+        "\"on-confirm\""
+    );
+    assert_eq!(
+        serde_json::to_string(&AckMode::NoAck).unwrap(),
+        "\"no-ack\""
+    );
+    assert_eq!(
+        serde_json::from_str::<AckMode>("\"on-publish\"").unwrap(),
+        AckMode::OnPublish
+    );
+    assert_eq!(AckMode::from("no-ack"), AckMode::NoAck);
+    assert_eq!(String::from(AckMode::OnPublish), "on-publish");
+}
+
+#[test]
+fn test_deserialize_definitions() {
+    let json = include_str!("fixtures/definitions.json");
+    let result = serde_json::from_str::<Definitions>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize definitions: {:?}",
+        result
+    );
+
+    let defs = result.unwrap();
+    assert_eq!(defs.server_version, Some("3.13.1".to_owned()));
+    assert_eq!(defs.policies.len(), 1);
+    assert_eq!(defs.policies[0].apply_to, PolicyTarget::QuorumQueues);
+    assert_eq!(defs.parameters[0].component, "federation-upstream");
+    assert_eq!(defs.queues[0].name, "orders.fulfillment");
+    assert_eq!(defs.exchanges[0].exchange_type, ExchangeType::Topic);
+    assert_eq!(
+        defs.bindings[0].destination_type,
+        BindingDestinationType::Queue
+    );
+    assert_eq!(defs.vhosts[0].name, "/");
+    assert_eq!(
+        defs.vhosts[0].metadata.description.as_deref(),
+        Some("Default virtual host")
+    );
+    assert_eq!(defs.users[0].tags, vec!["administrator"]);
+    assert_eq!(defs.permissions[0].user, "guest");
+    assert_eq!(defs.global_parameters[0].name, "cluster_name");
+}
+
+#[test]
+fn test_definitions_to_json() {
+    let json = include_str!("fixtures/definitions.json");
+    let defs = serde_json::from_str::<Definitions>(json).unwrap();
+
+    let doc = defs.to_json();
+    assert_eq!(doc["rabbitmq_version"], json!("3.13.1"));
+    assert_eq!(doc["policies"][0]["apply-to"], json!("quorum_queues"));
+    assert_eq!(doc["exchanges"][0]["type"], json!("topic"));
+    assert_eq!(doc["users"][0]["tags"], json!(["administrator"]));
+
+    let reparsed = serde_json::from_value::<Definitions>(doc).unwrap();
+    assert_eq!(reparsed.queues, defs.queues);
+    assert_eq!(reparsed.policies, defs.policies);
+    assert_eq!(reparsed.vhosts, defs.vhosts);
+}
+
+#[test]
+fn test_deserialize_definitions_with_comma_separated_user_tags() {
+    let json =
+        r#"{"users": [{"name": "ops", "password_hash": "", "tags": "administrator, monitoring"}]}"#;
+    let defs = serde_json::from_str::<Definitions>(json).unwrap();
+    assert_eq!(defs.users[0].tags, vec!["administrator", "monitoring"]);
+}
+
+#[test]
+fn test_deserialize_partial_definitions() {
+    let result = serde_json::from_str::<Definitions>(r#"{"queues": []}"#);
+    assert!(result.is_ok());
+
+    let defs = result.unwrap();
+    assert!(defs.server_version.is_none());
+    assert!(defs.policies.is_empty());
+    assert!(defs.bindings.is_empty());
+}
+
+#[test]
+fn test_import_plan_compute() {
+    let json = include_str!("fixtures/definitions.json");
+    let current = serde_json::from_str::<Definitions>(json).unwrap();
+
+    let mut desired = current.clone();
+    desired.queues[0].durable = false;
+    desired.exchanges[0].name = "orders.v2".to_owned();
+
+    let plan = ImportPlan::compute(&desired, &current);
+    assert!(plan.has_changes());
+    assert!(plan.has_conflicts());
+    // queues cannot be redeclared with different properties
+    assert!(plan.queues.to_update.is_empty());
+    assert_eq!(plan.queues.conflicting.len(), 1);
+    assert_eq!(plan.exchanges.to_create.len(), 1);
+    assert_eq!(plan.exchanges.to_create[0].name, "orders.v2");
+    assert_eq!(plan.policies.unchanged.len(), 1);
+    assert_eq!(plan.bindings.unchanged.len(), 1);
+    assert!(!plan.parameters.has_changes());
+
+    let noop = ImportPlan::compute(&current, &current);
+    assert!(!noop.has_changes());
+    assert!(!noop.has_conflicts());
+}
+
+#[test]
+fn test_deserialize_connection_without_tuning_fields() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/connection.json")).unwrap();
+    let obj = value.as_object_mut().unwrap();
+    obj.remove("timeout");
+    obj.remove("frame_max");
+
+    let result = serde_json::from_value::<Connection>(value);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a connection: {:?}",
+        result
+    );
+    let c = result.unwrap();
+    assert!(c.heartbeat.is_none());
+    assert!(c.frame_max.is_none());
+}
+
+#[test]
+fn test_deserialize_connection_with_partial_capabilities() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/connection.json")).unwrap();
+    let capabilities = value["client_properties"]["capabilities"]
+        .as_object_mut()
+        .unwrap();
+    capabilities.remove("publisher_confirms");
+    capabilities.remove("authentication_failure_close");
+
+    let result = serde_json::from_value::<Connection>(value);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a connection: {:?}",
+        result
+    );
+    let capabilities = result.unwrap().client_properties.capabilities.unwrap();
+    assert!(!capabilities.publisher_confirms);
+    assert!(!capabilities.authentication_failure_close);
+    assert!(capabilities.basic_nack);
+}
+
+#[test]
+fn test_binding_argument_equivalence() {
+    let json = include_str!("fixtures/bindings.json");
+    let vec = serde_json::from_str::<Vec<BindingInfo>>(json).unwrap();
+    let headers_binding = &vec[1];
+
+    let same: Map<String, Value> =
+        serde_json::from_str(r#"{"region": "eu", "x-match": "all"}"#).unwrap();
+    assert!(headers_binding.has_equivalent_arguments(&same));
+
+    let different: Map<String, Value> =
+        serde_json::from_str(r#"{"region": "us", "x-match": "all"}"#).unwrap();
+    assert!(!headers_binding.has_equivalent_arguments(&different));
+
+    let subset: Map<String, Value> = serde_json::from_str(r#"{"x-match": "all"}"#).unwrap();
+    assert!(!headers_binding.has_equivalent_arguments(&subset));
+
+    let mut numeric = vec[0].clone();
+    numeric.arguments = serde_json::from_str(r#"{"x-priority": 1}"#).unwrap();
+    let as_float: Map<String, Value> = serde_json::from_str(r#"{"x-priority": 1.0}"#).unwrap();
+    assert!(numeric.has_equivalent_arguments(&as_float));
+}
+
+#[test]
+fn test_deserialize_channel_without_message_counters() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/channel.json")).unwrap();
+    let obj = value.as_object_mut().unwrap();
+    obj.remove("messages_unacknowledged");
+    obj.remove("messages_unconfirmed");
+
+    let result = serde_json::from_value::<Channel>(value);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize a channel: {:?}",
+        result
+    );
+    assert_eq!(result.unwrap().messages_unacknowledged, 0);
+}
+
+#[test]
+fn test_deserialize_limits_with_empty_list_value() {
+    let json =
+        r#"[{"vhost": "/", "value": []}, {"vhost": "limited", "value": {"max-queues": 10}}]"#;
+    let result = serde_json::from_str::<Vec<VirtualHostLimits>>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize virtual host limits: {:?}",
+        result
+    );
+    let limits = result.unwrap();
+    assert!(limits[0].limits.is_empty());
+    assert_eq!(
+        limits[1].limits.get("max-queues").unwrap().as_u64(),
+        Some(10)
+    );
+
+    let json = r#"{"user": "guest", "vhost": "/", "value": []}"#;
+    let result = serde_json::from_str::<UserLimits>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize user limits: {:?}",
+        result
+    );
+    assert!(result.unwrap().limits.is_empty());
+
+    let json = r#"{"vhost": "/", "value": [1]}"#;
+    assert!(serde_json::from_str::<VirtualHostLimits>(json).is_err());
+}
+
+#[test]
+fn test_deserialize_sample_retention_policies() {
+    let json = r#"{"global": [600, 3600, 28800, 86400], "basic": [600, 3600], "detailed": [600]}"#;
+    let result = serde_json::from_str::<SampleRetentionPolicies>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize sample retention policies: {:?}",
+        result
+    );
+    let policies = result.unwrap();
+    assert_eq!(policies.global, vec![600, 3600, 28800, 86400]);
+    assert_eq!(policies.basic, vec![600, 3600]);
+    assert_eq!(policies.detailed, vec![600]);
+}
+
+#[test]
+fn test_deserialize_overview() {
+    let json = include_str!("fixtures/overview.json");
+    let result = serde_json::from_str::<Overview>(json);
+    assert!(
+        result.is_ok(),
+        "failed to deserialize overview: {:?}",
+        result
+    );
+
+    let overview = result.unwrap();
+    assert_eq!(overview.cluster_name, "rabbit@sunnyside");
+    assert_eq!(overview.rabbitmq_version, "3.13.1");
+    assert_eq!(overview.erlang_version, "26.2.4");
+    assert_eq!(overview.object_totals.queues, 5);
+    assert_eq!(overview.queue_totals.messages_ready, Some(90));
+    let stats = overview.message_stats.unwrap();
+    assert_eq!(stats.publish, Some(1200));
+}
+
+#[test]
+fn test_deserialize_overview_without_stats() {
+    let mut json: Value = serde_json::from_str(include_str!("fixtures/overview.json")).unwrap();
+    let obj = json.as_object_mut().unwrap();
+    obj.remove("message_stats");
+    obj.insert("queue_totals".to_owned(), Value::Object(Map::new()));
+
+    let overview: Overview = serde_json::from_value(json).unwrap();
+    assert!(overview.message_stats.is_none());
+    assert!(overview.queue_totals.messages.is_none());
+}
+
+#[test]
+fn test_consumer_connection_name() {
+    let json = r#"{
+        "arguments": {},
+        "ack_required": true,
+        "active": true,
+        "channel_details": {
+            "connection_name": "127.0.0.1:54210 -> 127.0.0.1:5672",
+            "name": "127.0.0.1:54210 -> 127.0.0.1:5672 (1)",
+            "node": "rabbit@sunnyvale",
+            "number": 1,
+            "peer_host": "127.0.0.1",
+            "peer_port": 54210,
+            "user": "guest"
+        },
+        "consumer_tag": "amq.ctag-Hvjz8VfPVUKLlhMqNc6Rsg",
+        "consumer_timeout": 1800000,
+        "exclusive": false,
+        "prefetch_count": 20,
+        "queue": {"name": "qq.1", "vhost": "/"}
+    }"#;
+    let consumer: Consumer = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        consumer.connection_name(),
+        "127.0.0.1:54210 -> 127.0.0.1:5672"
+    );
+}
+
+#[test]
+fn test_deserialize_aliveness_check_details() {
+    let ok: AlivenessCheckDetails = serde_json::from_str(r#"{"status": "ok"}"#).unwrap();
+    assert_eq!(ok.status, "ok");
+    assert!(ok.reason.is_none());
+
+    let failed: AlivenessCheckDetails =
+        serde_json::from_str(r#"{"status": "failed", "reason": "timeout"}"#).unwrap();
+    assert_eq!(failed.status, "failed");
+    assert_eq!(failed.reason.as_deref(), Some("timeout"));
+
+    // the status stands in for a missing reason
+    let unreachable: AlivenessCheckDetails =
+        serde_json::from_str(r#"{"status": "unreachable"}"#).unwrap();
+    assert_eq!(
+        HealthCheckFailureDetails::AlivenessCheck(unreachable).reason(),
+        "unreachable"
+    );
+    let err = Error::HealthCheckFailed(HealthCheckFailureDetails::AlivenessCheck(failed));
+    assert_eq!(err.to_string(), "Health check failed: timeout");
+}
@@ -1,8 +1,11 @@
-use rabbitmq_http_client::{blocking::Client, requests::ExchangeParams};
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    requests::{ExchangeParams, MessageProperties, QueueParams, VirtualHostParams},
+};
 use serde_json::{json, Map, Value};
 
 mod common;
-use crate::common::{endpoint, PASSWORD, USERNAME};
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
 
 #[test]
 fn test_declare_a_fanout_exchange() {
@@ -64,3 +67,175 @@ fn test_list_exchanges_in_a_virtual_host() {
     let result1 = rc.list_exchanges_in("/");
     assert!(result1.is_ok(), "list_exchanges_in returned {:?}", result1);
 }
+
+#[test]
+fn test_list_exchanges_with_params() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.list_exchanges_with_params("/", &[("sort", "name")]);
+    assert!(
+        result1.is_ok(),
+        "list_exchanges_with_params returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().iter().any(|x| x.name == "amq.fanout"));
+}
+
+fn alternate_exchange_args(ae: &str) -> Option<Map<String, Value>> {
+    let mut map = Map::<String, Value>::new();
+    map.insert("x-alternate-exchange".to_owned(), json!(ae));
+    Some(map)
+}
+
+#[test]
+fn test_alternate_exchange_chain() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let x1 = "rust.tests.ae_chain.1";
+    let x2 = "rust.tests.ae_chain.2";
+    let x3 = "rust.tests.ae_chain.3";
+
+    for (name, args) in [
+        (x1, alternate_exchange_args(x2)),
+        (x2, alternate_exchange_args(x3)),
+        (x3, None),
+    ] {
+        let _ = rc.delete_exchange(vhost, name);
+        rc.declare_exchange(vhost, &ExchangeParams::fanout(name, false, false, args))
+            .unwrap();
+    }
+
+    let result1 = rc.alternate_exchange_chain(vhost, x1);
+    assert!(
+        result1.is_ok(),
+        "alternate_exchange_chain returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap(), vec![x2, x3]);
+
+    let result2 = rc.alternate_exchange_chain(vhost, x3);
+    assert!(result2.is_ok());
+    assert!(result2.unwrap().is_empty());
+
+    for name in [x1, x2, x3] {
+        let _ = rc.delete_exchange(vhost, name);
+    }
+}
+
+#[test]
+fn test_alternate_exchange_chain_with_a_cycle() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let xa = "rust.tests.ae_cycle.a";
+    let xb = "rust.tests.ae_cycle.b";
+
+    for (name, ae) in [(xa, xb), (xb, xa)] {
+        let _ = rc.delete_exchange(vhost, name);
+        let params = ExchangeParams::fanout(name, false, false, alternate_exchange_args(ae));
+        rc.declare_exchange(vhost, &params).unwrap();
+    }
+
+    let result = rc.alternate_exchange_chain(vhost, xa);
+    assert!(
+        matches!(result, Err(Error::AlternateExchangeCycle(_))),
+        "alternate_exchange_chain returned {:?}",
+        result
+    );
+
+    for name in [xa, xb] {
+        let _ = rc.delete_exchange(vhost, name);
+    }
+}
+
+#[test]
+fn test_exchange_params_from_exchange_info_round_trip() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.topic.round_trip";
+
+    let vh_params = VirtualHostParams::named("test_exchange_params_round_trip");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let mut map = Map::<String, Value>::new();
+    map.insert("x-alternate-exchange".to_owned(), json!("amq.fanout"));
+    let params = ExchangeParams::durable_topic(name, Some(map));
+    let result2 = rc.declare_exchange(vhost, &params);
+    assert!(result2.is_ok(), "declare_exchange returned {:?}", result2);
+
+    let original = rc.get_exchange_info(vhost, name).unwrap();
+    let converted = ExchangeParams::from(&original);
+
+    // re-declaring with different properties would fail
+    let result3 = rc.declare_exchange(vhost, &converted);
+    assert!(result3.is_ok(), "declare_exchange returned {:?}", result3);
+
+    let result4 = rc.declare_exchange(vh_params.name, &converted);
+    assert!(result4.is_ok(), "declare_exchange returned {:?}", result4);
+    let copy = rc.get_exchange_info(vh_params.name, name).unwrap();
+    assert_eq!(copy.exchange_type, original.exchange_type);
+    assert_eq!(copy.durable, original.durable);
+    assert_eq!(copy.auto_delete, original.auto_delete);
+    assert_eq!(copy.arguments, original.arguments);
+
+    let _ = rc.delete_exchange(vhost, name);
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[test]
+fn test_publish_message() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.publish_message.1";
+
+    let _ = rc.delete_queue(vhost, queue);
+    rc.declare_queue(vhost, &QueueParams::new_durable_classic_queue(queue, None))
+        .unwrap();
+
+    let mut props = MessageProperties::with_app_id("rust.tests");
+    props.user_id = Some(USERNAME);
+    let result1 = rc.publish_message(vhost, "amq.default", queue, "hello", &props);
+    assert!(result1.is_ok(), "publish_message returned {:?}", result1);
+    assert!(result1.unwrap());
+
+    let result2 = rc.publish_message(vhost, "amq.default", "no.such.queue", "hello", &props);
+    assert!(result2.is_ok(), "publish_message returned {:?}", result2);
+    assert!(!result2.unwrap());
+
+    props.user_id = Some("not-the-publishing-user");
+    let result3 = rc.publish_message(vhost, "amq.default", queue, "hello", &props);
+    assert!(
+        matches!(result3, Err(Error::UserIdMismatch { .. })),
+        "publish_message returned {:?}",
+        result3
+    );
+
+    let _ = rc.delete_queue(vhost, queue);
+}
+
+#[test]
+fn test_publish_message_as_impersonator() {
+    let server = serve_fixtures(vec![
+        (
+            "/api/whoami",
+            json!({"name": USERNAME, "tags": ["impersonator"]}).to_string(),
+        ),
+        (
+            "/api/exchanges/%2F/amq%2Edefault/publish",
+            json!({"routed": true}).to_string(),
+        ),
+    ]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let mut props = MessageProperties::with_app_id("rust.tests");
+    props.user_id = Some("not-the-publishing-user");
+    let result = rc.publish_message("/", "amq.default", "orders", "hello", &props);
+    assert!(result.is_ok(), "publish_message returned {:?}", result);
+    assert!(result.unwrap());
+}
@@ -0,0 +1,83 @@
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    commons::{FeatureFlagStability, FeatureFlagState},
+};
+
+mod common;
+use crate::common::{endpoint, PASSWORD, USERNAME};
+
+#[test]
+fn test_list_feature_flags() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.list_feature_flags();
+    assert!(result.is_ok(), "list_feature_flags returned {:?}", result);
+    let flags = result.unwrap();
+    assert!(flags
+        .iter()
+        .any(|ff| ff.name == "quorum_queue" && ff.state == FeatureFlagState::Enabled));
+}
+
+#[test]
+fn test_enable_feature_flag() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    // enabling an enabled flag is a no-op
+    let result1 = rc.enable_feature_flag("quorum_queue");
+    assert!(
+        result1.is_ok(),
+        "enable_feature_flag returned {:?}",
+        result1
+    );
+
+    let result2 = rc.enable_feature_flag("rust_tests_no_such_feature_flag");
+    assert!(
+        matches!(result2, Err(Error::FeatureFlagNotEnabled { .. })),
+        "enable_feature_flag returned {:?}",
+        result2
+    );
+}
+
+#[test]
+fn test_list_feature_flags_by_stability() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.list_stable_feature_flags();
+    assert!(
+        result1.is_ok(),
+        "list_stable_feature_flags returned {:?}",
+        result1
+    );
+    let stable = result1.unwrap();
+    assert!(stable.iter().any(|ff| ff.name == "quorum_queue"));
+    assert!(stable
+        .iter()
+        .all(|ff| ff.stability != FeatureFlagStability::Experimental));
+
+    let result2 = rc.list_experimental_feature_flags();
+    assert!(
+        result2.is_ok(),
+        "list_experimental_feature_flags returned {:?}",
+        result2
+    );
+    let experimental = result2.unwrap();
+    assert!(experimental
+        .iter()
+        .all(|ff| ff.stability == FeatureFlagStability::Experimental));
+
+    // experimental flags cannot be disabled, so only check that they are refused
+    if let Some(ff) = experimental
+        .iter()
+        .find(|ff| ff.state == FeatureFlagState::Disabled)
+    {
+        let result3 = rc.enable_feature_flag(&ff.name);
+        assert!(
+            matches!(result3, Err(Error::ExperimentalFeatureFlag { .. })),
+            "enable_feature_flag returned {:?}",
+            result3
+        );
+    }
+}
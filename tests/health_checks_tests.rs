@@ -1,4 +1,9 @@
-use rabbitmq_http_client::blocking::Client;
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    commons::TimeUnit,
+    requests::QueueParams,
+    responses::HealthCheckFailureDetails,
+};
 
 mod common;
 use crate::common::{endpoint, PASSWORD, USERNAME};
@@ -29,3 +34,125 @@ fn test_health_check_node_is_quorum_critical() {
     let result1 = rc.health_check_if_node_is_quorum_critical();
     assert!(result1.is_ok());
 }
+
+#[test]
+fn test_health_check_below_vhost_queue_count() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_below_vhost_queue_count(100_000);
+    assert!(result1.is_ok());
+
+    let vh = "/";
+    let q = "rust.tests.cq.health_check_below_vhost_queue_count";
+    let _ = rc.declare_queue(vh, &QueueParams::new_durable_classic_queue(q, None));
+
+    let result2 = rc.health_check_below_vhost_queue_count(1);
+    assert!(result2.is_err());
+
+    let _ = rc.delete_queue(vh, q);
+}
+
+#[test]
+fn test_health_check_aliveness() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_aliveness("/");
+    assert!(
+        result1.is_ok(),
+        "health_check_aliveness returned {:?}",
+        result1
+    );
+}
+
+#[test]
+fn test_health_check_virtual_hosts() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_virtual_hosts();
+    assert!(
+        result1.is_ok(),
+        "health_check_virtual_hosts returned {:?}",
+        result1
+    );
+}
+
+#[test]
+fn test_health_check_node_is_in_service() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_node_is_in_service();
+    assert!(
+        result1.is_ok(),
+        "health_check_node_is_in_service returned {:?}",
+        result1
+    );
+}
+
+#[test]
+fn test_health_check_protocol_listener() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_protocol_listener("amqp");
+    assert!(
+        result1.is_ok(),
+        "health_check_protocol_listener returned {:?}",
+        result1
+    );
+
+    let result2 = rc.health_check_protocol_listener("rust-tests-no-such-protocol");
+    assert!(
+        matches!(
+            result2,
+            Err(Error::HealthCheckFailed(
+                HealthCheckFailureDetails::NoActiveProtocolListener(_)
+            ))
+        ),
+        "health_check_protocol_listener returned {:?}",
+        result2
+    );
+}
+
+#[test]
+fn test_health_check_port_listener() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_port_listener(5672);
+    assert!(
+        result1.is_ok(),
+        "health_check_port_listener returned {:?}",
+        result1
+    );
+
+    // nothing listens on this port
+    let result2 = rc.health_check_port_listener(1);
+    assert!(
+        matches!(
+            result2,
+            Err(Error::HealthCheckFailed(
+                HealthCheckFailureDetails::NoActivePortListener(_)
+            ))
+        ),
+        "health_check_port_listener returned {:?}",
+        result2
+    );
+}
+
+#[test]
+fn test_health_check_certificate_expiration() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    // the test node has no TLS-enabled listeners
+    let result = rc.health_check_certificate_expiration(1, TimeUnit::Months);
+    assert!(
+        result.is_ok(),
+        "health_check_certificate_expiration returned {:?}",
+        result
+    );
+}
@@ -14,6 +14,19 @@ fn test_list_nodes() {
     assert!(vec.iter().any(|n| n.name.starts_with("rabbit@")))
 }
 
+#[test]
+fn test_list_node_names() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let result = rc.list_node_names();
+
+    assert!(result.is_ok(), "list_node_names returned {:?}", result);
+    let names = result.unwrap();
+    let nodes = rc.list_nodes().unwrap();
+    assert_eq!(names.len(), nodes.len());
+    assert!(names.iter().all(|n| n.starts_with("rabbit@")))
+}
+
 #[test]
 fn test_get_node_info() {
     let endpoint = endpoint();
@@ -26,3 +39,17 @@ fn test_get_node_info() {
     assert!(node.uptime >= 1);
     assert!(node.total_erlang_processes >= 1);
 }
+
+#[test]
+fn test_reset_stats() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result1 = rc.reset_stats();
+    assert!(result1.is_ok(), "reset_stats returned {:?}", result1);
+
+    let nodes = rc.list_nodes().unwrap();
+    let name = nodes.first().unwrap().name.clone();
+    let result2 = rc.reset_node_stats(&name);
+    assert!(result2.is_ok(), "reset_node_stats returned {:?}", result2);
+}
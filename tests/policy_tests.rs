@@ -1,7 +1,7 @@
 use rabbitmq_http_client::{
     blocking::Client,
     commons::PolicyTarget,
-    requests::{PolicyParams, VirtualHostParams},
+    requests::{ExchangeParams, PolicyParams, QueueParams, VirtualHostParams},
 };
 
 use serde_json::{json, Map, Value};
@@ -128,3 +128,296 @@ fn test_an_operator_policy(rc: &Client, policy: &PolicyParams) {
     let policies = rc.list_operator_policies().unwrap();
     assert!(!policies.iter().any(|p| p.name == policy.name));
 }
+
+#[test]
+fn test_effective_exchange_policy() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_effective_exchange_policy");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let x1 = "test_effective_exchange_policy.with_ae";
+    let x2 = "test_effective_exchange_policy.plain";
+    rc.declare_exchange(
+        vh_params.name,
+        &ExchangeParams::fanout(x1, true, false, None),
+    )
+    .unwrap();
+    rc.declare_exchange(
+        vh_params.name,
+        &ExchangeParams::fanout(x2, true, false, None),
+    )
+    .unwrap();
+
+    let mut map = Map::<String, Value>::new();
+    map.insert("alternate-exchange".to_owned(), json!("amq.fanout"));
+    let ae_policy = PolicyParams {
+        vhost: vh_params.name,
+        name: "ae_policy",
+        pattern: "^test_effective_exchange_policy\\.with_ae$",
+        apply_to: PolicyTarget::Exchanges,
+        priority: 0,
+        definition: Some(map),
+    };
+    rc.declare_policy(&ae_policy).unwrap();
+    common::await_metric_emission(1000);
+
+    let result2 = rc.effective_exchange_policy(vh_params.name, x1);
+    assert!(
+        result2.is_ok(),
+        "effective_exchange_policy returned {:?}",
+        result2
+    );
+    let policy = result2.unwrap();
+    assert!(policy.is_some());
+    assert_eq!(policy.unwrap().name, ae_policy.name);
+
+    let result3 = rc.effective_exchange_policy(vh_params.name, x2);
+    assert!(result3.is_ok());
+    assert!(result3.unwrap().is_none());
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[test]
+fn test_list_policies_in_priority_order() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_policies_in_priority_order");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    for (name, priority) in [("policy_b", 5), ("policy_c", 10), ("policy_a", 5)] {
+        let mut map = Map::<String, Value>::new();
+        map.insert("max-length".to_owned(), json!(1_000));
+        let params = PolicyParams {
+            vhost: vh_params.name,
+            name,
+            pattern: ".*",
+            apply_to: PolicyTarget::Queues,
+            priority,
+            definition: Some(map),
+        };
+        rc.declare_policy(&params).unwrap();
+    }
+
+    let result2 = rc.list_policies_in_priority_order(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "list_policies_in_priority_order returned {:?}",
+        result2
+    );
+    let names: Vec<String> = result2.unwrap().into_iter().map(|p| p.name).collect();
+    assert_eq!(names, vec!["policy_c", "policy_a", "policy_b"]);
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_policy_pattern_validation() {
+    let valid = PolicyParams {
+        vhost: "/",
+        name: "valid_pattern",
+        pattern: "^events\\.",
+        apply_to: PolicyTarget::Exchanges,
+        priority: 0,
+        definition: None,
+    };
+    assert!(valid.validate().is_ok());
+
+    let invalid = PolicyParams {
+        vhost: "/",
+        name: "invalid_pattern",
+        pattern: "^events.(",
+        apply_to: PolicyTarget::Exchanges,
+        priority: 0,
+        definition: None,
+    };
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_policy_for_exact_name() {
+    let params = PolicyParams::for_exact_name(
+        "/",
+        "exact_name_policy",
+        "orders.v1",
+        PolicyTarget::Queues,
+        0,
+        None,
+    );
+    assert_eq!(params.pattern(), "^orders\\.v1$");
+    assert_eq!(params.params().pattern, "^orders\\.v1$");
+}
+
+#[test]
+fn test_declare_policy_for_exact_name() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_declare_policy_for_exact_name");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let mut map = Map::<String, Value>::new();
+    map.insert("max-length".to_owned(), json!(10_000));
+    let params = PolicyParams::for_exact_name(
+        vh_params.name,
+        "exact_name_policy",
+        "orders.v1",
+        PolicyTarget::Queues,
+        0,
+        Some(map),
+    );
+    test_a_policy(&rc, &params.params());
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[test]
+fn test_list_queues_with_policy() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_queues_with_policy");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let q1 = "governed.q1";
+    let q2 = "ungoverned.q2";
+    for name in [q1, q2] {
+        rc.declare_queue(
+            vh_params.name,
+            &QueueParams::new_durable_classic_queue(name, None),
+        )
+        .unwrap();
+    }
+
+    let mut map = Map::<String, Value>::new();
+    map.insert("max-length".to_owned(), json!(1_000));
+    let params = PolicyParams {
+        vhost: vh_params.name,
+        name: "governed_queues",
+        pattern: "^governed\\.",
+        apply_to: PolicyTarget::Queues,
+        priority: 0,
+        definition: Some(map),
+    };
+    rc.declare_policy(&params).unwrap();
+    common::await_metric_emission(1000);
+
+    let result2 = rc.list_queues_with_policy(vh_params.name, params.name);
+    assert!(
+        result2.is_ok(),
+        "list_queues_with_policy returned {:?}",
+        result2
+    );
+    let names: Vec<String> = result2.unwrap().into_iter().map(|q| q.name).collect();
+    assert_eq!(names, vec![q1]);
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
+#[test]
+fn test_policy_params_from_policy_round_trip() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params1 = VirtualHostParams::named("test_policy_params_round_trip.1");
+    let vh_params2 = VirtualHostParams::named("test_policy_params_round_trip.2");
+    for vh in [&vh_params1, &vh_params2] {
+        let _ = rc.delete_vhost(vh.name);
+        let result = rc.create_vhost(vh);
+        assert!(result.is_ok(), "create_vhost returned {:?}", result);
+    }
+
+    let mut map = Map::<String, Value>::new();
+    map.insert("max-length".to_owned(), json!(1000));
+    map.insert("overflow".to_owned(), json!("reject-publish"));
+    let params = PolicyParams {
+        vhost: vh_params1.name,
+        name: "round_trip_policy",
+        pattern: "^orders\\.",
+        apply_to: PolicyTarget::QuorumQueues,
+        priority: 7,
+        definition: Some(map),
+    };
+    let result1 = rc.declare_policy(&params);
+    assert!(result1.is_ok(), "declare_policy returned {:?}", result1);
+
+    let original = rc.get_policy(vh_params1.name, params.name).unwrap();
+    let mut converted = PolicyParams::from(&original);
+    converted.vhost = vh_params2.name;
+    let result2 = rc.declare_policy(&converted);
+    assert!(result2.is_ok(), "declare_policy returned {:?}", result2);
+
+    let copy = rc.get_policy(vh_params2.name, params.name).unwrap();
+    assert_eq!(copy.pattern, original.pattern);
+    assert_eq!(copy.apply_to, original.apply_to);
+    assert_eq!(copy.priority, original.priority);
+    assert_eq!(copy.definition, original.definition);
+
+    let _ = rc.delete_vhost(vh_params1.name);
+    let _ = rc.delete_vhost(vh_params2.name);
+}
+
+#[test]
+fn test_clear_all_policies_in() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_clear_all_policies_in");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    for name in ["policy_a", "policy_b"] {
+        let mut map = Map::<String, Value>::new();
+        map.insert("max-length".to_owned(), json!(1_000));
+        let params = PolicyParams {
+            vhost: vh_params.name,
+            name,
+            pattern: ".*",
+            apply_to: PolicyTarget::Queues,
+            priority: 0,
+            definition: Some(map),
+        };
+        rc.declare_policy(&params).unwrap();
+        rc.declare_operator_policy(&params).unwrap();
+    }
+
+    let result2 = rc.clear_all_policies_in(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "clear_all_policies_in returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), 2);
+    assert!(rc.list_policies_in(vh_params.name).unwrap().is_empty());
+
+    let result3 = rc.clear_all_operator_policies_in(vh_params.name);
+    assert!(
+        result3.is_ok(),
+        "clear_all_operator_policies_in returned {:?}",
+        result3
+    );
+    assert_eq!(result3.unwrap(), 2);
+    assert!(rc
+        .list_operator_policies_in(vh_params.name)
+        .unwrap()
+        .is_empty());
+
+    // there is nothing left to delete
+    let result4 = rc.clear_all_policies_in(vh_params.name);
+    assert_eq!(result4.unwrap(), 0);
+
+    let _ = rc.delete_vhost(vh_params.name);
+}
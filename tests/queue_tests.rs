@@ -1,4 +1,8 @@
-use rabbitmq_http_client::{blocking::Client, requests::QueueParams};
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    commons::QueueType,
+    requests::{QueueParams, VirtualHostParams},
+};
 use serde_json::{json, Map, Value};
 
 mod common;
@@ -106,3 +110,367 @@ fn test_list_queues_in_a_virtual_host() {
 
     rc.delete_queue(vh_name, params.name).unwrap();
 }
+
+#[test]
+fn test_queues_with_leader_on() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let qq = "rust.tests.qq.queues_with_leader_on";
+    let cq = "rust.tests.cq.queues_with_leader_on";
+
+    let _ = rc.delete_queue(vhost, qq);
+    let _ = rc.delete_queue(vhost, cq);
+
+    let result1 = rc.declare_queue(vhost, &QueueParams::new_quorum_queue(qq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+    let result2 = rc.declare_queue(vhost, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result2.is_ok(), "declare_queue returned {:?}", result2);
+
+    common::await_queue_metric_emission();
+
+    let node = rc.list_nodes().unwrap().first().unwrap().name.clone();
+    let result3 = rc.queues_with_leader_on(&node);
+    assert!(
+        result3.is_ok(),
+        "queues_with_leader_on returned {:?}",
+        result3
+    );
+    let vec = result3.unwrap();
+    assert!(vec.iter().any(|q| q.name == qq));
+    assert!(!vec.iter().any(|q| q.name == cq));
+
+    let _ = rc.delete_queue(vhost, qq);
+    let _ = rc.delete_queue(vhost, cq);
+}
+
+#[test]
+fn test_under_replicated_quorum_queues() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let qq = "rust.tests.qq.under_replicated_quorum_queues";
+
+    let _ = rc.delete_queue(vhost, qq);
+    let result1 = rc.declare_queue(vhost, &QueueParams::new_quorum_queue(qq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    let cluster_size = rc.list_nodes().unwrap().len();
+
+    let result2 = rc.under_replicated_quorum_queues(cluster_size);
+    assert!(
+        result2.is_ok(),
+        "under_replicated_quorum_queues returned {:?}",
+        result2
+    );
+    assert!(!result2.unwrap().iter().any(|q| q.name == qq));
+
+    let result3 = rc.under_replicated_quorum_queues(cluster_size + 1);
+    assert!(result3.is_ok());
+    assert!(result3.unwrap().iter().any(|q| q.name == qq));
+
+    let _ = rc.delete_queue(vhost, qq);
+}
+
+#[test]
+fn test_list_queues_with_params() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+
+    let params =
+        QueueParams::new_durable_classic_queue("rust.tests.cq.list_queues_with_params", None);
+    let result1 = rc.declare_queue(vh_name, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    let result2 =
+        rc.list_queues_with_params(vh_name, &[("sort", "name"), ("sort_reverse", "true")]);
+    assert!(
+        result2.is_ok(),
+        "list_queues_with_params returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().iter().any(|q| q.name == params.name));
+
+    rc.delete_queue(vh_name, params.name).unwrap();
+}
+
+#[test]
+fn test_list_queues_with_columns() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+
+    let params =
+        QueueParams::new_durable_classic_queue("rust.tests.cq.list_queues_with_columns", None);
+    let result1 = rc.declare_queue(vh_name, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    let result2 = rc.list_queues_with_columns::<Map<String, Value>>(&["name", "vhost"]);
+    assert!(
+        result2.is_ok(),
+        "list_queues_with_columns returned {:?}",
+        result2
+    );
+    let queues = result2.unwrap();
+    assert!(queues.iter().all(|q| q.len() == 2));
+    assert!(queues
+        .iter()
+        .any(|q| q.get("name") == Some(&json!(params.name))));
+
+    rc.delete_queue(vh_name, params.name).unwrap();
+}
+
+#[test]
+fn test_declare_queue_checked_reports_differing_fields() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let name = "rust.tests.cq.declare_queue_checked";
+
+    let _ = rc.delete_queue(vh_name, name);
+
+    let mut args = Map::<String, Value>::new();
+    args.insert("x-max-length".to_owned(), json!(10));
+    let params = QueueParams::new_durable_classic_queue(name, Some(args));
+    let result1 = rc.declare_queue_checked(vh_name, &params);
+    assert!(
+        result1.is_ok(),
+        "declare_queue_checked returned {:?}",
+        result1
+    );
+
+    // an equivalent declaration is a no-op
+    let result2 = rc.declare_queue_checked(vh_name, &params);
+    assert!(
+        result2.is_ok(),
+        "declare_queue_checked returned {:?}",
+        result2
+    );
+
+    let mut args = Map::<String, Value>::new();
+    args.insert("x-max-length".to_owned(), json!(20));
+    let params2 = QueueParams::new_durable_classic_queue(name, Some(args));
+    let result3 = rc.declare_queue_checked(vh_name, &params2);
+    match result3 {
+        Err(Error::Inequivalent {
+            existing,
+            requested,
+            differing_fields,
+        }) => {
+            assert_eq!(existing.name, name);
+            assert_eq!(requested.name, name);
+            assert_eq!(differing_fields, vec!["arguments.x-max-length"]);
+        }
+        other => panic!("declare_queue_checked returned {:?}", other),
+    }
+
+    rc.delete_queue(vh_name, name).unwrap();
+}
+
+#[test]
+fn test_list_exclusive_queues() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+
+    let params =
+        QueueParams::new_durable_classic_queue("rust.tests.cq.list_exclusive_queues", None);
+    let result1 = rc.declare_queue(vh_name, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    let result2 = rc.list_exclusive_queues(Some(vh_name));
+    assert!(
+        result2.is_ok(),
+        "list_exclusive_queues returned {:?}",
+        result2
+    );
+    let queues = result2.unwrap();
+    assert!(queues.iter().all(|q| q.exclusive && q.vhost == vh_name));
+    assert!(!queues.iter().any(|q| q.name == params.name));
+
+    let result3 = rc.list_exclusive_queues(None);
+    assert!(
+        result3.is_ok(),
+        "list_exclusive_queues returned {:?}",
+        result3
+    );
+    assert!(result3.unwrap().iter().all(|q| q.exclusive));
+
+    rc.delete_queue(vh_name, params.name).unwrap();
+}
+
+#[test]
+fn test_get_queue_replica_statuses() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let params = QueueParams::new_quorum_queue("rust.tests.qq.replica_statuses", None);
+    let result1 = rc.declare_queue(vh_name, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    let result2 = rc.get_queue_replica_statuses(vh_name, params.name);
+    assert!(
+        result2.is_ok(),
+        "get_queue_replica_statuses returned {:?}",
+        result2
+    );
+    let replicas = result2.unwrap();
+    assert!(!replicas.is_empty());
+    assert!(replicas.iter().all(|r| r.online));
+
+    rc.delete_queue(vh_name, params.name).unwrap();
+}
+
+#[test]
+fn test_queue_needs_more_consumers() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.cq.queue_needs_more_consumers";
+
+    let _ = rc.delete_queue(vhost, name);
+    let params = QueueParams::new_durable_classic_queue(name, None);
+    let result1 = rc.declare_queue(vhost, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    common::await_queue_metric_emission();
+
+    // an empty queue is below any positive backlog threshold
+    let result2 = rc.queue_needs_more_consumers(vhost, name, 1, 0.9);
+    assert!(
+        result2.is_ok(),
+        "queue_needs_more_consumers returned {:?}",
+        result2
+    );
+    assert!(!result2.unwrap());
+
+    // with a zero threshold, a queue without consumers needs some
+    let result3 = rc.queue_needs_more_consumers(vhost, name, 0, 0.9);
+    assert!(result3.is_ok());
+    assert!(result3.unwrap());
+
+    let _ = rc.delete_queue(vhost, name);
+}
+
+#[test]
+fn test_recreate_queue() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.cq.recreate_queue";
+
+    let _ = rc.delete_queue(vhost, name);
+    let params = QueueParams::new_durable_classic_queue(name, None);
+
+    // works when the queue does not exist yet
+    let result1 = rc.recreate_queue(vhost, &params);
+    assert!(result1.is_ok(), "recreate_queue returned {:?}", result1);
+
+    rc.bind_queue(vhost, name, "amq.direct", Some("routing.key"), None)
+        .unwrap();
+
+    let result2 = rc.recreate_queue(vhost, &params);
+    assert!(result2.is_ok(), "recreate_queue returned {:?}", result2);
+    let bindings = rc
+        .list_queue_bindings_excluding_default(vhost, name)
+        .unwrap();
+    assert!(bindings.is_empty());
+
+    let _ = rc.delete_queue(vhost, name);
+}
+
+#[test]
+fn test_recreate_queue_preserving_bindings() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let vhost = "/";
+    let name = "rust.tests.cq.recreate_queue_preserving_bindings";
+
+    let _ = rc.delete_queue(vhost, name);
+    let params = QueueParams::new_durable_classic_queue(name, None);
+    rc.declare_queue(vhost, &params).unwrap();
+    rc.bind_queue(vhost, name, "amq.direct", Some("routing.key"), None)
+        .unwrap();
+
+    let result = rc.recreate_queue_preserving_bindings(vhost, &params);
+    assert!(
+        result.is_ok(),
+        "recreate_queue_preserving_bindings returned {:?}",
+        result
+    );
+    let bindings = rc
+        .list_queue_bindings_excluding_default(vhost, name)
+        .unwrap();
+    assert!(bindings
+        .iter()
+        .any(|b| b.source == "amq.direct" && b.routing_key == "routing.key"));
+
+    let _ = rc.delete_queue(vhost, name);
+}
+
+#[test]
+fn test_list_queues_of_type() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_queues_of_type");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let vhost = vh_params.name;
+    let cq = "rust.tests.cq.of_type";
+    let qq = "rust.tests.qq.of_type";
+    let sq = "rust.tests.sq.of_type";
+    rc.declare_queue(vhost, &QueueParams::new_durable_classic_queue(cq, None))
+        .unwrap();
+    rc.declare_queue(vhost, &QueueParams::new_quorum_queue(qq, None))
+        .unwrap();
+    rc.declare_queue(vhost, &QueueParams::new_stream(sq, None))
+        .unwrap();
+
+    for (queue_type, expected) in [
+        (QueueType::Classic, cq),
+        (QueueType::Quorum, qq),
+        (QueueType::Stream, sq),
+    ] {
+        let result = rc.list_queues_of_type_in(vhost, queue_type);
+        assert!(
+            result.is_ok(),
+            "list_queues_of_type_in returned {:?}",
+            result
+        );
+        let names: Vec<String> = result.unwrap().into_iter().map(|q| q.name).collect();
+        assert_eq!(names, vec![expected]);
+    }
+
+    let result2 = rc.list_queues_of_type(QueueType::Quorum);
+    assert!(
+        result2.is_ok(),
+        "list_queues_of_type returned {:?}",
+        result2
+    );
+    let quorum_queues = result2.unwrap();
+    assert!(quorum_queues.iter().any(|q| q.name == qq));
+    assert!(quorum_queues
+        .iter()
+        .all(|q| q.queue_type == QueueType::Quorum));
+
+    let _ = rc.delete_vhost(vhost);
+}
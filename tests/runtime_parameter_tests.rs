@@ -68,6 +68,36 @@ fn test_clear_runtime_parameter() {
     let _ = rc.delete_vhost(vh_params.name);
 }
 
+#[test]
+fn test_list_runtime_parameter_components() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_runtime_parameter_components");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let mut val = max_connections_limit(9988);
+    let rpf = example_runtime_parameter_definition(vh_params.name, &mut val);
+    let result2 = rc.upsert_runtime_parameter(&rpf);
+    assert!(result2.is_ok());
+
+    let result3 = rc.list_runtime_parameter_components();
+    assert!(
+        result3.is_ok(),
+        "list_runtime_parameter_components returned {:?}",
+        result3
+    );
+    let components = result3.unwrap();
+    assert_eq!(
+        components.iter().filter(|c| *c == "vhost-limits").count(),
+        1
+    );
+
+    let _ = rc.clear_runtime_parameter(&rpf.component, &rpf.vhost, &rpf.name);
+    let _ = rc.delete_vhost(vh_params.name);
+}
+
 //
 // Implementation
 //
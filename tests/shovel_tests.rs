@@ -0,0 +1,95 @@
+use rabbitmq_http_client::{
+    blocking::Client,
+    requests::{MessageProperties, QueueParams, ShovelDeleteAfter, ShovelParams},
+};
+use std::time::Duration;
+
+mod common;
+use crate::common::{endpoint, PASSWORD, USERNAME};
+
+#[test]
+fn test_declare_and_delete_shovel() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vhost = "/";
+    let name = "rust.tests.shovel.declare_and_delete";
+    let src_q = "rust.tests.cq.shovel.src";
+    let dest_q = "rust.tests.cq.shovel.dest";
+    let uri = "amqp://localhost:5672";
+
+    let result1 = rc.declare_queue(vhost, &QueueParams::new_durable_classic_queue(src_q, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let mut params = ShovelParams::queue_to_queue(vhost, name, uri, src_q, uri, dest_q);
+    params.prefetch_count = Some(100);
+    params.delete_after = ShovelDeleteAfter::QueueLength;
+    let result2 = rc.declare_shovel(&params);
+    assert!(result2.is_ok(), "declare_shovel returned {:?}", result2);
+
+    let result3 = rc.get_runtime_parameter("shovel", vhost, name);
+    assert!(
+        result3.is_ok(),
+        "get_runtime_parameter returned {:?}",
+        result3
+    );
+    let value = result3.unwrap().value;
+    assert_eq!(value.get("src-queue").unwrap().as_str(), Some(src_q));
+    assert_eq!(value.get("dest-queue").unwrap().as_str(), Some(dest_q));
+    assert_eq!(value.get("src-prefetch-count").unwrap().as_u64(), Some(100));
+
+    let result4 = rc.list_shovels();
+    assert!(result4.is_ok(), "list_shovels returned {:?}", result4);
+
+    let result5 = rc.delete_shovel(vhost, name);
+    assert!(result5.is_ok(), "delete_shovel returned {:?}", result5);
+
+    let _ = rc.delete_queue(vhost, src_q);
+    let _ = rc.delete_queue(vhost, dest_q);
+}
+
+#[test]
+fn test_shovel_messages_once() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vhost = "/";
+    let src_q = "rust.tests.cq.shovel_once.src";
+    let dest_q = "rust.tests.cq.shovel_once.dest";
+    for q in [src_q, dest_q] {
+        let _ = rc.delete_queue(vhost, q);
+        rc.declare_queue(vhost, &QueueParams::new_durable_classic_queue(q, None))
+            .unwrap();
+    }
+    let props = MessageProperties::default();
+    for _ in 0..3 {
+        rc.publish_message(vhost, "amq.default", src_q, "hello", &props)
+            .unwrap();
+    }
+    common::await_metric_emission(1000);
+
+    let result1 = rc.shovel_messages_once(vhost, src_q, dest_q, Duration::from_secs(30));
+    assert!(
+        result1.is_ok(),
+        "shovel_messages_once returned {:?}",
+        result1
+    );
+    let transfer = result1.unwrap();
+    assert!(
+        transfer.completed,
+        "transfer did not complete: {:?}",
+        transfer
+    );
+    assert_eq!(transfer.source_message_count, 3);
+
+    // the temporary shovel is gone
+    let shovels = rc.list_shovels().unwrap();
+    assert!(!shovels.iter().any(|s| s.name == transfer.shovel_name));
+
+    common::await_metric_emission(1000);
+    let dest = rc.get_queue_info(vhost, dest_q).unwrap();
+    assert_eq!(dest.message_count, 3);
+
+    let _ = rc.delete_queue(vhost, src_q);
+    let _ = rc.delete_queue(vhost, dest_q);
+}
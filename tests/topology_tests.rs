@@ -0,0 +1,162 @@
+use rabbitmq_http_client::{
+    blocking::Client,
+    requests::{ExchangeParams, QueueParams, VirtualHostParams},
+};
+use serde_json::{json, Value};
+
+mod common;
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
+
+fn fixture_named(fixture: &str, vhost: &str, name: &str) -> Value {
+    let mut value: Value = serde_json::from_str(fixture).unwrap();
+    value["vhost"] = json!(vhost);
+    value["name"] = json!(name);
+    value
+}
+
+fn topology_routes(vhosts: &[&str], queues: Vec<Value>) -> Vec<(&'static str, String)> {
+    let vhosts: Vec<Value> = vhosts
+        .iter()
+        .map(|name| {
+            let mut vh: Value = serde_json::from_str(include_str!("fixtures/vhost.json")).unwrap();
+            vh["name"] = json!(name);
+            vh
+        })
+        .collect();
+    let exchange = fixture_named(include_str!("fixtures/exchange_info.json"), "/", "events");
+    vec![
+        ("/api/vhosts", json!(vhosts).to_string()),
+        ("/api/queues", json!(queues).to_string()),
+        ("/api/exchanges", json!([exchange]).to_string()),
+        ("/api/bindings", "[]".to_owned()),
+        ("/api/policies", "[]".to_owned()),
+    ]
+}
+
+#[test]
+fn test_compare_topology_of_different_clusters() {
+    let queue = include_str!("fixtures/queue_info_with_consumers.json");
+    let mut limited1 = fixture_named(queue, "/", "limited");
+    limited1["arguments"]["x-max-length"] = json!(10);
+    let mut limited2 = fixture_named(queue, "/", "limited");
+    limited2["arguments"]["x-max-length"] = json!(20);
+
+    let this_server = serve_fixtures(topology_routes(
+        &["/", "staging"],
+        vec![
+            fixture_named(queue, "/", "shared"),
+            fixture_named(queue, "/", "only.this"),
+            fixture_named(queue, "staging", "staged"),
+            limited1,
+        ],
+    ));
+    let other_server = serve_fixtures(topology_routes(
+        &["/"],
+        vec![
+            fixture_named(queue, "/", "shared"),
+            fixture_named(queue, "/", "only.other"),
+            limited2,
+        ],
+    ));
+    let rc = Client::new(&this_server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let other = Client::new(&other_server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.compare_topology(&other);
+    assert!(result.is_ok(), "compare_topology returned {:?}", result);
+
+    let diff = result.unwrap();
+    let key = |vhost: &str, name: &str| (vhost.to_owned(), name.to_owned());
+    assert_eq!(diff.virtual_hosts_only_in_this, vec!["staging"]);
+    assert!(diff.virtual_hosts_only_in_other.is_empty());
+    assert_eq!(
+        diff.queues.only_in_this,
+        vec![key("/", "only.this"), key("staging", "staged")]
+    );
+    assert_eq!(diff.queues.only_in_other, vec![key("/", "only.other")]);
+    assert_eq!(diff.queues.different, vec![key("/", "limited")]);
+    assert!(diff.exchanges.is_empty());
+    assert!(!diff.is_empty());
+
+    // a cluster compared with an identical one
+    let result2 = rc.compare_topology(&rc);
+    assert!(result2.is_ok(), "compare_topology returned {:?}", result2);
+    assert!(result2.unwrap().is_empty());
+}
+
+#[test]
+fn test_compare_topology_of_a_cluster_with_itself() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let other = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let q = "rust.tests.cq.compare_topology";
+    let x = "rust.tests.fanout.compare_topology";
+
+    let _ = rc.delete_queue(vh_name, q);
+    let _ = rc.delete_exchange(vh_name, x);
+
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(q, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+    let result2 = rc.declare_exchange(vh_name, &ExchangeParams::durable_fanout(x, None));
+    assert!(result2.is_ok(), "declare_exchange returned {:?}", result2);
+
+    let result3 = rc.compare_topology(&other);
+    assert!(result3.is_ok(), "compare_topology returned {:?}", result3);
+
+    // other tests may declare and delete objects concurrently,
+    // so only the objects declared above are checked
+    let diff = result3.unwrap();
+    let qk = (vh_name.to_owned(), q.to_owned());
+    let xk = (vh_name.to_owned(), x.to_owned());
+    assert!(!diff.queues.only_in_this.contains(&qk));
+    assert!(!diff.queues.only_in_other.contains(&qk));
+    assert!(!diff.queues.different.contains(&qk));
+    assert!(!diff.exchanges.only_in_this.contains(&xk));
+    assert!(!diff.exchanges.different.contains(&xk));
+    assert!(!diff
+        .virtual_hosts_only_in_this
+        .contains(&vh_name.to_owned()));
+
+    rc.delete_queue(vh_name, q).unwrap();
+    rc.delete_exchange(vh_name, x).unwrap();
+}
+
+#[test]
+fn test_topology_snapshot() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_topology_snapshot");
+    let _ = rc.delete_vhost(vh_params.name);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let vh_name = vh_params.name;
+    let q = "rust.tests.cq.topology_snapshot";
+    let x = "rust.tests.fanout.topology_snapshot";
+    rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(q, None))
+        .unwrap();
+    rc.declare_exchange(vh_name, &ExchangeParams::durable_fanout(x, None))
+        .unwrap();
+    rc.bind_queue(vh_name, q, x, None, None).unwrap();
+
+    let result2 = rc.topology_snapshot(Some(vh_name));
+    assert!(result2.is_ok(), "topology_snapshot returned {:?}", result2);
+    let snapshot = result2.unwrap();
+
+    // bound to the fanout and, implicitly, the default exchange
+    let queue = snapshot.find_queue(vh_name, q).unwrap();
+    let mut sources: Vec<&str> = queue.bindings.iter().map(|b| b.source.as_str()).collect();
+    sources.sort();
+    assert_eq!(sources, vec!["", x]);
+
+    let exchange = snapshot.find_exchange(vh_name, x).unwrap();
+    assert_eq!(exchange.bindings.len(), 1);
+    assert_eq!(exchange.bindings[0].destination, q);
+
+    let default_exchange = snapshot.find_exchange(vh_name, "").unwrap();
+    assert_eq!(default_exchange.bindings.len(), 1);
+
+    let _ = rc.delete_vhost(vh_name);
+}
@@ -83,3 +83,38 @@ fn test_list_user_limits() {
 
     rc.delete_user(params.name).unwrap();
 }
+
+#[test]
+fn test_clear_all_user_limits() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let salt = password_hashing::salt();
+    let password_hash =
+        password_hashing::base64_encoded_salted_password_hash_sha256(&salt, "rust3_t0p_sEkr37");
+
+    let params = UserParams {
+        name: "test_clear_all_user_limits",
+        password_hash: &password_hash,
+        tags: "management",
+    };
+    let result1 = rc.create_user(&params);
+    assert!(result1.is_ok());
+
+    let limit1 = EnforcedLimitParams::new(UserLimitTarget::MaxChannels, 500);
+    rc.set_user_limit(params.name, limit1).unwrap();
+    let limit2 = EnforcedLimitParams::new(UserLimitTarget::MaxConnections, 500);
+    rc.set_user_limit(params.name, limit2).unwrap();
+
+    let result2 = rc.clear_all_user_limits(params.name);
+    assert!(
+        result2.is_ok(),
+        "clear_all_user_limits returned {:?}",
+        result2
+    );
+
+    let vec = rc.list_user_limits(params.name).unwrap();
+    assert!(vec.iter().all(|it| it.limits.is_empty()));
+
+    rc.delete_user(params.name).unwrap();
+}
@@ -1,4 +1,9 @@
-use rabbitmq_http_client::{blocking::Client, password_hashing, requests::UserParams};
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    commons::UserTag,
+    password_hashing,
+    requests::UserParams,
+};
 
 mod common;
 use crate::common::{endpoint, PASSWORD, USERNAME};
@@ -65,3 +70,152 @@ fn test_user_deletion() {
     let result2 = rc.delete_user(name);
     assert!(result2.is_ok());
 }
+
+#[test]
+fn test_bulk_user_creation() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let salt = password_hashing::salt();
+    let password_hash =
+        password_hashing::base64_encoded_salted_password_hash_sha256(&salt, "bulk_t0p_sEkr37");
+
+    let users = [
+        UserParams {
+            name: "test_bulk_user_creation.1",
+            password_hash: &password_hash,
+            tags: "management",
+        },
+        UserParams {
+            name: "test_bulk_user_creation.2",
+            password_hash: &password_hash,
+            tags: "monitoring",
+        },
+    ];
+    let results = rc.create_users(&users);
+    assert_eq!(users.len(), results.len());
+    assert!(
+        results.iter().all(|r| r.is_ok()),
+        "create_users returned {:?}",
+        results
+    );
+
+    for u in users.iter() {
+        assert!(rc.get_user(u.name).is_ok());
+        rc.delete_user(u.name).unwrap();
+    }
+}
+
+#[test]
+fn test_list_users_with_tag() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let salt = password_hashing::salt();
+    let password_hash =
+        password_hashing::base64_encoded_salted_password_hash_sha256(&salt, "m0n1t0r_sEkr37");
+
+    let params = UserParams {
+        name: "test_list_users_with_tag",
+        password_hash: &password_hash,
+        tags: "monitoring",
+    };
+    let result1 = rc.create_user(&params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.list_users_with_tag(&UserTag::Monitoring);
+    assert!(
+        result2.is_ok(),
+        "list_users_with_tag returned {:?}",
+        result2
+    );
+    let vec = result2.unwrap();
+    assert!(vec.iter().any(|u| u.name == params.name));
+
+    let result3 = rc.list_users_with_tag(&UserTag::Administrator);
+    assert!(
+        result3.is_ok(),
+        "list_users_with_tag returned {:?}",
+        result3
+    );
+    let vec = result3.unwrap();
+    assert!(!vec.iter().any(|u| u.name == params.name));
+    assert!(vec.iter().any(|u| u.name == "guest"));
+
+    rc.delete_user(params.name).unwrap();
+}
+
+#[test]
+fn test_authentication_failure() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, "wr0ng_pa$$w0rd");
+
+    let result = rc.list_nodes();
+    assert!(
+        matches!(result, Err(Error::AuthenticationFailed(_))),
+        "list_nodes returned {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_export_user() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.export_user(USERNAME);
+    assert!(result.is_ok(), "export_user returned {:?}", result);
+
+    let export = result.unwrap();
+    assert_eq!(export.user.name, USERNAME);
+    assert!(export.permissions.iter().any(|p| p.vhost == "/"));
+    assert!(export.permissions.iter().all(|p| p.user == USERNAME));
+    assert!(export.topic_permissions.iter().all(|p| p.user == USERNAME));
+}
+
+#[test]
+fn test_whoami() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.whoami();
+    assert!(result.is_ok(), "whoami returned {:?}", result);
+    let user = result.unwrap();
+    assert_eq!(user.name, USERNAME);
+    assert!(user.tags.contains(&"administrator".to_owned()));
+}
+
+#[test]
+fn test_authenticate() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let name = "rust.tests.authenticate";
+    let password = "auth3nt1cat3_me";
+    let salt = password_hashing::salt();
+    let password_hash =
+        password_hashing::base64_encoded_salted_password_hash_sha256(&salt, password);
+    let params = UserParams {
+        name,
+        password_hash: &password_hash,
+        tags: "monitoring",
+    };
+    let _ = rc.delete_user(name);
+    rc.create_user(&params).unwrap();
+
+    let result1 = rc.authenticate(name, password);
+    assert!(result1.is_ok(), "authenticate returned {:?}", result1);
+    let user = result1.unwrap().unwrap();
+    assert_eq!(user.name, name);
+    assert_eq!(user.tags, vec!["monitoring"]);
+
+    let result2 = rc.authenticate(name, "not-the-password");
+    assert!(result2.is_ok(), "authenticate returned {:?}", result2);
+    assert!(result2.unwrap().is_none());
+
+    // the client's own credentials are not affected
+    let result3 = rc.whoami();
+    assert_eq!(result3.unwrap().name, USERNAME);
+
+    let _ = rc.delete_user(name);
+}
@@ -65,3 +65,106 @@ fn test_list_vhost_limits() {
 
     rc.delete_vhost(vh_params.name).unwrap();
 }
+
+#[test]
+fn test_clear_all_vhost_limits() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_clear_all_vhost_limits");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let limit1 = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxConnections, 500);
+    rc.set_vhost_limit(vh_params.name, limit1).unwrap();
+    let limit2 = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxQueues, 500);
+    rc.set_vhost_limit(vh_params.name, limit2).unwrap();
+
+    let result2 = rc.clear_all_vhost_limits(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "clear_all_vhost_limits returned {:?}",
+        result2
+    );
+
+    let vec = rc.list_vhost_limits(vh_params.name).unwrap();
+    assert!(vec.iter().all(|it| it.limits.is_empty()));
+
+    rc.delete_vhost(vh_params.name).unwrap();
+}
+
+#[test]
+fn test_set_vhost_limits() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_set_vhost_limits");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let limit = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxQueues, 10);
+    rc.set_vhost_limit(vh_params.name, limit).unwrap();
+
+    // replaces the max-queues limit set above
+    let result2 = rc.set_vhost_limits(
+        vh_params.name,
+        vec![EnforcedLimitParams::new(
+            VirtualHostLimitTarget::MaxConnections,
+            100,
+        )],
+    );
+    assert!(result2.is_ok(), "set_vhost_limits returned {:?}", result2);
+
+    let vec = rc.list_vhost_limits(vh_params.name).unwrap();
+    let key1 = VirtualHostLimitTarget::MaxConnections.to_string();
+    assert!(vec
+        .iter()
+        .any(|it| it.vhost == vh_params.name && it.limits.get(&key1).is_some()));
+    let key2 = VirtualHostLimitTarget::MaxQueues.to_string();
+    assert!(!vec
+        .iter()
+        .any(|it| it.vhost == vh_params.name && it.limits.get(&key2).is_some()));
+
+    // limits set either way can be cleared either way
+    let result3 = rc.clear_vhost_limit(vh_params.name, VirtualHostLimitTarget::MaxConnections);
+    assert!(result3.is_ok(), "clear_vhost_limit returned {:?}", result3);
+    let vec = rc.list_vhost_limits(vh_params.name).unwrap();
+    assert!(vec.iter().all(|it| it.limits.is_empty()));
+
+    rc.delete_vhost(vh_params.name).unwrap();
+}
+
+#[test]
+fn test_list_connection_tracking() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_list_connection_tracking");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let result2 = rc.list_connection_tracking(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "list_connection_tracking returned {:?}",
+        result2
+    );
+    let tracking = result2.unwrap();
+    assert_eq!(tracking.max_connections, None);
+    assert_eq!(tracking.connection_count, 0);
+    assert!(!tracking.is_over_limit());
+
+    let limit = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxConnections, 100);
+    let result3 = rc.set_vhost_limit(vh_params.name, limit);
+    assert!(result3.is_ok(), "set_vhost_limit returned {:?}", result3);
+
+    let result4 = rc.list_connection_tracking(vh_params.name);
+    assert!(
+        result4.is_ok(),
+        "list_connection_tracking returned {:?}",
+        result4
+    );
+    assert_eq!(result4.unwrap().max_connections, Some(100));
+
+    rc.delete_vhost(vh_params.name).unwrap();
+}
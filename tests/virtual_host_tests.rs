@@ -1,7 +1,19 @@
-use rabbitmq_http_client::{blocking::Client, commons::QueueType, requests::VirtualHostParams};
+use rabbitmq_http_client::{
+    blocking::{Client, Error},
+    commons::QueueType,
+    requests::VirtualHostParams,
+};
+use serde_json::{json, Value};
 
 mod common;
-use crate::common::{endpoint, PASSWORD, USERNAME};
+use crate::common::{endpoint, serve_fixtures, PASSWORD, USERNAME};
+
+fn vhost_fixture(name: &str, default_queue_type: &str) -> Value {
+    let mut vh: Value = serde_json::from_str(include_str!("fixtures/vhost.json")).unwrap();
+    vh["name"] = json!(name);
+    vh["default_queue_type"] = json!(default_queue_type);
+    vh
+}
 
 #[test]
 fn test_list_vhosts() {
@@ -118,3 +130,104 @@ fn test_delete_vhost() {
     let result3 = rc.get_vhost(name);
     assert!(result3.is_err());
 }
+
+#[test]
+fn test_list_vhosts_with_default_queue_type() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+    let name = "rust_test_list_vhosts_with_default_queue_type";
+
+    let _ = rc.delete_vhost(name);
+
+    let params = VirtualHostParams {
+        name,
+        description: None,
+        tags: None,
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: false,
+    };
+    let result1 = rc.create_vhost(&params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.list_vhosts_with_default_queue_type(QueueType::Quorum);
+    assert!(
+        result2.is_ok(),
+        "list_vhosts_with_default_queue_type returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().iter().any(|vh| vh.name == name));
+
+    let result3 = rc.list_vhosts_with_default_queue_type(QueueType::Stream);
+    assert!(result3.is_ok());
+    assert!(!result3.unwrap().iter().any(|vh| vh.name == name));
+
+    let _ = rc.delete_vhost(name);
+}
+
+#[test]
+fn test_set_cluster_default_queue_type() {
+    let vhosts = json!([
+        vhost_fixture("/", "classic"),
+        vhost_fixture("orders", "quorum"),
+        vhost_fixture("events", "classic"),
+    ]);
+    // there is no route for updating "events", so that update fails
+    let server = serve_fixtures(vec![
+        (
+            "/api/overview",
+            json!({"rabbitmq_version": "3.13.1"}).to_string(),
+        ),
+        ("/api/vhosts", vhosts.to_string()),
+        ("/api/vhosts/%2F", "{}".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+    let result = rc.set_cluster_default_queue_type(QueueType::Quorum);
+    assert!(
+        result.is_ok(),
+        "set_cluster_default_queue_type returned {:?}",
+        result
+    );
+    let failed = result.unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].0, "events");
+    assert!(matches!(failed[0].1, Error::ClientErrorResponse(404, _)));
+
+    // virtual hosts that already use the type are not updated
+    let requests = server.requests();
+    assert!(requests.contains(&"PUT /api/vhosts/%2F".to_owned()));
+    assert!(requests.contains(&"PUT /api/vhosts/events".to_owned()));
+    assert!(!requests.contains(&"PUT /api/vhosts/orders".to_owned()));
+}
+
+#[test]
+fn test_set_cluster_default_queue_type_version_check() {
+    for (version, supported) in [
+        ("3.10.25", false),
+        ("3.11.0", true),
+        ("4.0.0-rc.1", true),
+        ("v3.12.0", true),
+        ("", false),
+    ] {
+        let server = serve_fixtures(vec![
+            (
+                "/api/overview",
+                json!({"rabbitmq_version": version}).to_string(),
+            ),
+            ("/api/vhosts", "[]".to_owned()),
+        ]);
+        let rc = Client::new(&server.endpoint).with_basic_auth_credentials(USERNAME, PASSWORD);
+
+        let result = rc.set_cluster_default_queue_type(QueueType::Quorum);
+        if supported {
+            assert!(result.is_ok(), "{}: returned {:?}", version, result);
+        } else {
+            assert!(
+                matches!(result, Err(Error::UnsupportedServerVersion { .. })),
+                "{}: returned {:?}",
+                version,
+                result
+            );
+        }
+    }
+}